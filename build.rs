@@ -0,0 +1,54 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+/// Discover every `src/dayN/mod.rs`, check that `src/lib.rs` declares each of them as
+/// `pub mod dayN;` and generate the day registry.
+///
+/// Each day module is expected to expose a `DayN` type implementing `Solution`.
+fn main() -> Result<(), Box<dyn Error>> {
+    println!("cargo::rerun-if-changed=src");
+
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR")?).join("src");
+    let mut days = BTreeSet::new();
+    for entry in fs::read_dir(&src)? {
+        let path = entry?.path();
+        let day: Option<u8> = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|number| number.parse().ok());
+        if let Some(day) = day
+            && path.join("mod.rs").is_file()
+        {
+            days.insert(day);
+        }
+    }
+
+    let lib = fs::read_to_string(src.join("lib.rs"))?;
+    let declared: BTreeSet<u8> = lib
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("pub mod day")?.strip_suffix(';'))
+        .filter_map(|number| number.parse().ok())
+        .collect();
+    if let Some(day) = days.difference(&declared).next() {
+        return Err(
+            format!("src/day{day} is not declared, add `pub mod day{day};` to src/lib.rs").into(),
+        );
+    }
+
+    let mut registry = String::from("static DAYS: &[Entry] = &[\n");
+    for day in days {
+        writeln!(
+            registry,
+            "    Entry::new::<crate::day{day}::Day{day}>({day}),"
+        )?;
+    }
+    registry.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR")?;
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry)?;
+    Ok(())
+}
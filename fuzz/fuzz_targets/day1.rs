@@ -0,0 +1,22 @@
+#![no_main]
+
+use advent_of_code_2025::day1::{Day1, RotationDirection};
+use advent_of_code_2025::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for line in input.lines() {
+        let _ = line.parse::<RotationDirection>();
+    }
+    let parsed = Day1::parse(input);
+    let read = Day1::read(input.as_bytes());
+    assert_eq!(
+        parsed.as_ref().ok(),
+        read.as_ref().ok(),
+        "read and parse disagree"
+    );
+    if let Ok(rotations) = parsed {
+        let _ = Day1::part_one(&rotations);
+        let _ = Day1::part_two(&rotations);
+    }
+});
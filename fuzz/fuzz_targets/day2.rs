@@ -0,0 +1,30 @@
+#![no_main]
+
+use advent_of_code_2025::day2::{Day2, ProductIdRange};
+use advent_of_code_2025::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+/// Most ids to check, the solvers check every id of every range.
+const MAX_IDS: usize = 100_000;
+
+fuzz_target!(|input: &str| {
+    let mut ids = 0;
+    for range in input.split([',', '\n']) {
+        if let Ok(range) = range.parse::<ProductIdRange>() {
+            ids += range.into_iter().take(MAX_IDS + 1).count();
+        }
+    }
+    let parsed = Day2::parse(input);
+    let read = Day2::read(input.as_bytes());
+    assert_eq!(
+        parsed.as_ref().ok(),
+        read.as_ref().ok(),
+        "read and parse disagree"
+    );
+    if let Ok(database) = parsed
+        && ids <= MAX_IDS
+    {
+        let _ = Day2::part_one(&database);
+        let _ = Day2::part_two(&database);
+    }
+});
@@ -0,0 +1,22 @@
+#![no_main]
+
+use advent_of_code_2025::day3::{Bank, Day3};
+use advent_of_code_2025::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for line in input.lines() {
+        let _ = line.parse::<Bank>();
+    }
+    let parsed = Day3::parse(input);
+    let read = Day3::read(input.as_bytes());
+    assert_eq!(
+        parsed.as_ref().ok(),
+        read.as_ref().ok(),
+        "read and parse disagree"
+    );
+    if let Ok(banks) = parsed {
+        let _ = Day3::part_one(&banks);
+        let _ = Day3::part_two(&banks);
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use advent_of_code_2025::day4::Day4;
+use advent_of_code_2025::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let parsed = Day4::parse(input);
+    let read = Day4::read(input.as_bytes());
+    assert_eq!(
+        parsed.as_ref().ok(),
+        read.as_ref().ok(),
+        "read and parse disagree"
+    );
+    if let Ok(paper_storage) = parsed {
+        let _ = Day4::part_one(&paper_storage);
+        let _ = Day4::part_two(&paper_storage);
+    }
+});
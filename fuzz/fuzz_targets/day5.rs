@@ -0,0 +1,23 @@
+#![no_main]
+
+use advent_of_code_2025::day5::{Day5, IngredientId, IngredientIdRange};
+use advent_of_code_2025::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for line in input.lines() {
+        let _ = line.parse::<IngredientIdRange>();
+        let _ = line.parse::<IngredientId>();
+    }
+    let parsed = Day5::parse(input);
+    let read = Day5::read(input.as_bytes());
+    assert_eq!(
+        parsed.as_ref().ok(),
+        read.as_ref().ok(),
+        "read and parse disagree"
+    );
+    if let Ok(database) = parsed {
+        let _ = Day5::part_one(&database);
+        let _ = Day5::part_two(&database);
+    }
+});
@@ -0,0 +1,22 @@
+#![no_main]
+
+use advent_of_code_2025::day6::{Day6, Operation};
+use advent_of_code_2025::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for token in input.split_whitespace() {
+        let _ = token.parse::<Operation>();
+    }
+    let parsed = Day6::parse(input);
+    let read = Day6::read(input.as_bytes());
+    assert_eq!(
+        parsed.as_ref().ok(),
+        read.as_ref().ok(),
+        "read and parse disagree"
+    );
+    if let Ok(homework) = parsed {
+        let _ = Day6::part_one(&homework);
+        let _ = Day6::part_two(&homework);
+    }
+});
@@ -0,0 +1,112 @@
+use crate::registry;
+use crate::solution::Part;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The expected answer of one part of one day for one input.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+impl FromStr for Answer {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        let [day, part, input, answer] = fields[..] else {
+            return Err(format!("Expected `day part input answer`, found `{s}`").into());
+        };
+        Ok(Answer {
+            day: day.parse()?,
+            part: Part::try_from(part.parse::<u8>()?)?,
+            input: input.to_string(),
+            answer: answer.to_string(),
+        })
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {} part {} with {}", self.day, self.part, self.input)
+    }
+}
+
+/// Parse an answers manifest: one [`Answer`] per line, blank lines and `#` comments are ignored.
+pub fn parse_manifest(manifest: &str) -> Result<Vec<Answer>, Box<dyn Error>> {
+    manifest
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|error| format!("answers manifest line {}: {error}", i + 1).into())
+        })
+        .collect()
+}
+
+/// Solve the part of the expected answer against `input` and compare the result.
+pub fn check(expected: &Answer, input: &str) -> Result<(), Box<dyn Error>> {
+    let entry = registry::find(expected.day)
+        .ok_or_else(|| format!("No solution for day {}", expected.day))?;
+    let answer = entry.solve(expected.part, &expected.input, input)?;
+    if answer != expected.answer {
+        return Err(format!("expected {}, got {answer}", expected.answer).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{Answer, check, parse_manifest};
+    use crate::input;
+    use crate::solution::Part;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn parses_manifest_skipping_comments() {
+        let manifest = "# day part input answer\n\n4 1 example 13\n";
+        let expected = vec![Answer {
+            day: 4,
+            part: Part::One,
+            input: String::from("example"),
+            answer: String::from("13"),
+        }];
+        assert_eq!(expected, parse_manifest(manifest).unwrap());
+    }
+
+    #[test]
+    fn rejects_incomplete_lines() {
+        let error = parse_manifest("4 1 example").unwrap_err();
+        assert!(error.to_string().starts_with("answers manifest line 1"));
+    }
+
+    /// Inputs missing from the input directory are skipped, so the examples are checked even
+    /// without access to the private puzzle inputs.
+    #[test]
+    fn every_answer_in_manifest_is_correct() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let manifest = fs::read_to_string(root.join("answers.txt")).unwrap();
+        let failures: Vec<String> = parse_manifest(&manifest)
+            .unwrap()
+            .iter()
+            .filter_map(|expected| {
+                let result = match input::load(expected.day, &expected.input) {
+                    Ok(input) => check(expected, &input),
+                    Err(error) if error.is_missing() => {
+                        eprintln!("skipping {expected}: {error}");
+                        Ok(())
+                    }
+                    Err(error) => Err(error.into()),
+                };
+                result.err().map(|error| format!("{expected}: {error}"))
+            })
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,409 @@
+use crate::bench::{Measurement, Stage};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use std::{fs, io};
+
+/// Percentage a stage may be slower than its baseline before it counts as a regression, generous
+/// enough to ride out the noise of a busy machine.
+pub const DEFAULT_THRESHOLD: f64 = 25.0;
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Corrupt {
+        path: PathBuf,
+        line: usize,
+        reason: String,
+    },
+}
+
+impl BaselineError {
+    /// Whether no baseline has been saved yet, as opposed to the baseline being broken.
+    pub fn is_missing(&self) -> bool {
+        matches!(self, BaselineError::Io { source, .. } if source.kind() == io::ErrorKind::NotFound)
+    }
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaselineError::Io { path, source } => {
+                write!(f, "could not access {}: {source}", path.display())
+            }
+            BaselineError::Corrupt { path, line, reason } => {
+                write!(f, "{}:{line}: {reason}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for BaselineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BaselineError::Io { source, .. } => Some(source),
+            BaselineError::Corrupt { .. } => None,
+        }
+    }
+}
+
+/// The median time of one stage of one day with the input called `input` when the baseline was
+/// recorded.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Timing {
+    pub day: u8,
+    pub input: String,
+    pub stage: Stage,
+    pub median: Duration,
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}",
+            self.day,
+            self.input,
+            self.stage,
+            self.median.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Timing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('\t').collect();
+        let [day, input, stage, median] = fields[..] else {
+            return Err(format!("expected four tab separated fields in `{s}`"));
+        };
+        Ok(Timing {
+            day: day.parse().map_err(|_| format!("invalid day `{day}`"))?,
+            input: input.to_string(),
+            stage: stage.parse()?,
+            median: median
+                .parse()
+                .map(Duration::from_nanos)
+                .map_err(|_| format!("invalid median `{median}`"))?,
+        })
+    }
+}
+
+/// Timings recorded by an earlier benchmark run that later runs are compared against.
+#[derive(Debug, PartialEq, Default)]
+pub struct Baseline {
+    timings: Vec<Timing>,
+}
+
+impl Baseline {
+    /// The medians of `measurements`, taken with the input called `input`.
+    pub fn from_measurements(input: &str, measurements: &[Measurement]) -> Self {
+        let timings = measurements
+            .iter()
+            .map(|measurement| Timing {
+                day: measurement.day,
+                input: input.to_string(),
+                stage: measurement.stage,
+                median: measurement.stats.median,
+            })
+            .collect();
+        Baseline { timings }
+    }
+
+    pub fn timings(&self) -> &[Timing] {
+        &self.timings
+    }
+
+    pub fn get(&self, day: u8, input: &str, stage: Stage) -> Option<Duration> {
+        self.timings
+            .iter()
+            .find(|timing| timing.day == day && timing.input == input && timing.stage == stage)
+            .map(|timing| timing.median)
+    }
+
+    /// Replace the timings of the stages `other` has timings for, keeping every other timing.
+    pub fn merge(&mut self, other: Baseline) {
+        for timing in other.timings {
+            let existing = self.timings.iter_mut().find(|existing| {
+                existing.day == timing.day
+                    && existing.input == timing.input
+                    && existing.stage == timing.stage
+            });
+            match existing {
+                Some(existing) => *existing = timing,
+                None => self.timings.push(timing),
+            }
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let contents = fs::read_to_string(path).map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let timings = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| {
+                line.parse().map_err(|reason| BaselineError::Corrupt {
+                    path: path.to_path_buf(),
+                    line: i + 1,
+                    reason,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Baseline { timings })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        fs::write(path, self.to_string()).map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Compare the medians of `measurements`, taken with the input called `input`, with the
+    /// baseline of the same input, in the order they were measured.
+    pub fn compare(&self, input: &str, measurements: &[Measurement]) -> Vec<Comparison> {
+        measurements
+            .iter()
+            .map(|measurement| Comparison {
+                day: measurement.day,
+                stage: measurement.stage,
+                baseline: self.get(measurement.day, input, measurement.stage),
+                current: measurement.stats.median,
+            })
+            .collect()
+    }
+}
+
+/// Record the medians of `measurements`, taken with the input called `input`, in the baseline at
+/// `save`, keeping its timings of other stages, and compare them with the baseline at `compare`.
+/// The baseline to compare with is loaded before saving, so that a run can compare with the
+/// baseline it updates.
+pub fn save_and_compare(
+    save: Option<&Path>,
+    compare: Option<&Path>,
+    input: &str,
+    measurements: &[Measurement],
+) -> Result<Option<Vec<Comparison>>, BaselineError> {
+    let compared = compare.map(Baseline::load).transpose()?;
+    if let Some(path) = save {
+        let mut saved = match Baseline::load(path) {
+            Ok(saved) => saved,
+            Err(error) if error.is_missing() => Baseline::default(),
+            Err(error) => return Err(error),
+        };
+        saved.merge(Baseline::from_measurements(input, measurements));
+        saved.save(path)?;
+    }
+    Ok(compared.map(|compared| compared.compare(input, measurements)))
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day\tinput\tstage\tmedian in nanoseconds")?;
+        for timing in &self.timings {
+            writeln!(f, "{timing}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The median of one stage now against its median in the baseline, if it was benchmarked then.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Option<Duration>,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// How much slower the stage got in percent, negative when it got faster.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_secs_f64();
+        (baseline > 0.0).then(|| (self.current.as_secs_f64() / baseline - 1.0) * 100.0)
+    }
+
+    /// Whether the stage got slower than the baseline by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+/// Render comparisons as a table for the terminal, marking regressions beyond `threshold`.
+pub fn table(comparisons: &[Comparison], threshold: f64) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>8}\n",
+        "day", "stage", "baseline", "median", "change"
+    );
+    for comparison in comparisons {
+        let baseline = match comparison.baseline {
+            Some(baseline) => format!("{baseline:.1?}"),
+            None => String::from("-"),
+        };
+        let change = match comparison.change() {
+            Some(change) => format!("{change:+.1}%"),
+            None => String::from("new"),
+        };
+        let marker = match comparison.is_regression(threshold) {
+            true => "  regression",
+            false => "",
+        };
+        let _ = writeln!(
+            table,
+            "{:>3}  {:<6}  {:>12}  {:>12}  {:>8}{marker}",
+            comparison.day,
+            comparison.stage.to_string(),
+            baseline,
+            format!("{:.1?}", comparison.current),
+            change
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::baseline::{Baseline, BaselineError, Comparison, save_and_compare};
+    use crate::bench::{Measurement, Stage, Stats};
+    use crate::solution::Part;
+    use std::fs;
+    use std::time::Duration;
+
+    fn measurement(day: u8, stage: Stage, micros: u64) -> Measurement {
+        Measurement {
+            day,
+            stage,
+            stats: Stats::from_samples(&[Duration::from_micros(micros)]),
+        }
+    }
+
+    #[test]
+    fn saved_baseline_loads_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.txt");
+        let baseline = Baseline::from_measurements(
+            "input.txt",
+            &[
+                measurement(3, Stage::Parse, 1100),
+                measurement(3, Stage::Solve(Part::Two), 103),
+            ],
+        );
+        baseline.save(&path).unwrap();
+        assert_eq!(
+            "# day\tinput\tstage\tmedian in nanoseconds\n3\tinput.txt\tparse\t1100000\n\
+             3\tinput.txt\tpart 2\t103000\n",
+            fs::read_to_string(&path).unwrap()
+        );
+        assert_eq!(baseline, Baseline::load(&path).unwrap());
+    }
+
+    #[test]
+    fn merge_replaces_only_the_stages_measured() {
+        let mut baseline = Baseline::from_measurements(
+            "input.txt",
+            &[
+                measurement(3, Stage::Parse, 100),
+                measurement(4, Stage::Parse, 200),
+            ],
+        );
+        baseline.merge(Baseline::from_measurements(
+            "example",
+            &[measurement(3, Stage::Parse, 1)],
+        ));
+        baseline.merge(Baseline::from_measurements(
+            "input.txt",
+            &[measurement(3, Stage::Parse, 150)],
+        ));
+        assert_eq!(3, baseline.timings().len());
+        let get = |day, input| baseline.get(day, input, Stage::Parse);
+        assert_eq!(Some(Duration::from_micros(150)), get(3, "input.txt"));
+        assert_eq!(Some(Duration::from_micros(200)), get(4, "input.txt"));
+        assert_eq!(Some(Duration::from_micros(1)), get(3, "example"));
+    }
+
+    #[test]
+    fn missing_baseline_is_told_apart_from_corrupt_one() {
+        let dir = tempfile::tempdir().unwrap();
+        let error = Baseline::load(&dir.path().join("baseline.txt")).unwrap_err();
+        assert!(error.is_missing());
+    }
+
+    #[test]
+    fn corrupt_baseline_reports_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.txt");
+        fs::write(
+            &path,
+            "# day\tinput\tstage\tmedian\n5\texample\tparse\t10\n5\texample\tpart 3\t10\n",
+        )
+        .unwrap();
+        let error = Baseline::load(&path).unwrap_err();
+        assert!(matches!(error, BaselineError::Corrupt { line: 3, .. }));
+        assert!(!error.is_missing());
+    }
+
+    #[test]
+    fn flags_stages_slower_than_threshold() {
+        let baseline = Baseline::from_measurements(
+            "input.txt",
+            &[
+                measurement(4, Stage::Parse, 100),
+                measurement(4, Stage::Solve(Part::One), 100),
+            ],
+        );
+        let comparisons = baseline.compare(
+            "input.txt",
+            &[
+                measurement(4, Stage::Parse, 110),
+                measurement(4, Stage::Solve(Part::One), 150),
+                measurement(5, Stage::Parse, 1000),
+            ],
+        );
+        let regressions: Vec<&Comparison> = comparisons
+            .iter()
+            .filter(|comparison| comparison.is_regression(25.0))
+            .collect();
+        assert_eq!(1, regressions.len());
+        assert_eq!(Stage::Solve(Part::One), regressions[0].stage);
+        assert_eq!(None, comparisons[2].change());
+        assert!(comparisons[0].is_regression(5.0));
+    }
+
+    #[test]
+    fn compares_with_baseline_from_before_saving_to_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.txt");
+        Baseline::from_measurements("input.txt", &[measurement(4, Stage::Parse, 100)])
+            .save(&path)
+            .unwrap();
+        let measurements = [measurement(4, Stage::Parse, 200)];
+        let comparisons =
+            save_and_compare(Some(&path), Some(&path), "input.txt", &measurements).unwrap();
+        assert!(comparisons.unwrap()[0].is_regression(25.0));
+        let saved = Baseline::load(&path).unwrap();
+        assert_eq!(
+            Some(Duration::from_micros(200)),
+            saved.get(4, "input.txt", Stage::Parse)
+        );
+    }
+
+    #[test]
+    fn compares_only_timings_of_the_same_input() {
+        let baseline =
+            Baseline::from_measurements("input.txt", &[measurement(4, Stage::Parse, 100)]);
+        let comparisons = baseline.compare("example", &[measurement(4, Stage::Parse, 1)]);
+        assert_eq!(None, comparisons[0].baseline);
+        let comparisons = baseline.compare("input.txt", &[measurement(4, Stage::Parse, 1)]);
+        assert_eq!(Some(Duration::from_micros(100)), comparisons[0].baseline);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::solution::{Part, Solution};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Write};
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// Runs before measuring, to warm up caches and the branch predictor.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// What is being timed: parsing the input, or solving one part from the parsed input.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            None if s == "parse" => Ok(Stage::Parse),
+            Some(("part", part)) => part
+                .parse::<u8>()
+                .ok()
+                .and_then(|part| Part::try_from(part).ok())
+                .map(Stage::Solve)
+                .ok_or_else(|| format!("invalid part `{part}`")),
+            _ => Err(format!("unknown stage `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = match n {
+            0 => Duration::ZERO,
+            n if n % 2 == 0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            n => sorted[n / 2],
+        };
+        let seconds: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = seconds.iter().sum::<f64>() / n.max(1) as f64;
+        let variance = seconds.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n.max(1) as f64;
+        Stats {
+            iterations: n,
+            min: sorted.first().copied().unwrap_or_default(),
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Time `f` over the configured number of iterations after warming up.
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let samples: Vec<Duration> = (0..config.iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// Time parsing and every implemented part of one day separately.
+pub fn bench<S: Solution>(
+    day: u8,
+    file_name: &str,
+    input: &str,
+    config: &Config,
+) -> Result<Vec<Measurement>, Box<dyn Error>> {
+    let parsed = S::parse(input).map_err(|error| error.diagnostic(file_name, input))?;
+    let mut measurements = vec![Measurement {
+        day,
+        stage: Stage::Parse,
+        stats: measure(config, || S::parse(black_box(input))),
+    }];
+    for &part in S::PARTS {
+        // Solve once outside the measurement so failures are reported instead of timed.
+        let stats = match part {
+            Part::One => {
+                S::part_one(&parsed)?;
+                measure(config, || S::part_one(black_box(&parsed)))
+            }
+            Part::Two => {
+                S::part_two(&parsed)?;
+                measure(config, || S::part_two(black_box(&parsed)))
+            }
+        };
+        measurements.push(Measurement {
+            day,
+            stage: Stage::Solve(part),
+            stats,
+        });
+    }
+    Ok(measurements)
+}
+
+/// Render measurements as a table for the terminal.
+pub fn table(measurements: &[Measurement]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}  {:>5}\n",
+        "day", "stage", "median", "min", "stddev", "runs"
+    );
+    for measurement in measurements {
+        let stats = &measurement.stats;
+        let _ = writeln!(
+            table,
+            "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}  {:>5}",
+            measurement.day,
+            measurement.stage.to_string(),
+            format!("{:.1?}", stats.median),
+            format!("{:.1?}", stats.min),
+            format!("{:.1?}", stats.stddev),
+            stats.iterations
+        );
+    }
+    table
+}
+
+/// Render measurements as a JSON array with durations in nanoseconds.
+pub fn to_json(measurements: &[Measurement]) -> String {
+    let objects: Vec<String> = measurements
+        .iter()
+        .map(|measurement| {
+            let stats = &measurement.stats;
+            format!(
+                "{{\"day\":{},\"stage\":\"{}\",\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\
+                 \"mean_ns\":{},\"stddev_ns\":{}}}",
+                measurement.day,
+                measurement.stage,
+                stats.iterations,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )
+        })
+        .collect();
+    format!("[{}]", objects.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{Config, Stage, Stats, bench, to_json};
+    use crate::day6::Day6;
+    use crate::solution::{Part, Solution};
+    use std::time::Duration;
+
+    #[test]
+    fn computes_statistics_of_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(4, stats.iterations);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_micros(2500), stats.median);
+        assert_eq!(Duration::from_micros(2500), stats.mean);
+        assert_eq!(1118, stats.stddev.as_micros());
+    }
+
+    #[test]
+    fn times_parsing_and_implemented_parts() {
+        let config = Config {
+            warmup: 1,
+            iterations: 2,
+        };
+        let measurements = bench::<Day6>(6, "example", Day6::EXAMPLE, &config).unwrap();
+        let stages: Vec<Stage> = measurements.iter().map(|m| m.stage).collect();
+        assert_eq!(vec![Stage::Parse, Stage::Solve(Part::One)], stages);
+        assert!(measurements.iter().all(|m| m.stats.iterations == 2));
+    }
+
+    #[test]
+    fn renders_json() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(10)]);
+        let measurement = crate::bench::Measurement {
+            day: 1,
+            stage: Stage::Solve(Part::Two),
+            stats,
+        };
+        let expected = "[{\"day\":1,\"stage\":\"part 2\",\"iterations\":1,\"min_ns\":10,\
+                        \"median_ns\":10,\"mean_ns\":10,\"stddev_ns\":0}]";
+        assert_eq!(expected, to_json(&[measurement]));
+    }
+}
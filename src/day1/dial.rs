@@ -1,9 +1,13 @@
 use crate::day1::rotation_direction::RotationDirection;
+use crate::render::{Canvas, Render, Style};
+use std::f64::consts::TAU;
 
-#[derive(Debug, PartialEq)]
+/// The dial after a rotation, remembering how many times that rotation passed zero.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dial {
     position: i32,
-    times_passed_zero: i32,
+    times_passed_zero: u32,
 }
 
 impl Dial {
@@ -15,54 +19,85 @@ impl Dial {
     }
 
     pub fn rotate(&self, direction: &RotationDirection) -> Dial {
-        let temp = match direction {
-            RotationDirection::Left(direction) => self.position - direction,
-            RotationDirection::Right(direction) => self.position + direction,
-        };
-
-        let position = temp % 100;
-        let revolutions = temp / 100;
-
-        let result = match position {
-            p if p < 0 => Dial {
-                position: position + 100,
-                times_passed_zero: if self.position != 0 {
-                    revolutions + 1
-                } else {
-                    revolutions
-                },
-            },
-            p => {
-                Dial {
-                    position: p,
-                    times_passed_zero: revolutions, //|| position == 0,
-                }
+        // Widened so that no amount of clicks overflows.
+        let position = i64::from(self.position);
+        let times_passed_zero = match *direction {
+            RotationDirection::Right(clicks) => (position + i64::from(clicks)) / 100,
+            // Turning left reaches zero after `position` clicks and then every 100 clicks,
+            // unless it starts at zero.
+            RotationDirection::Left(clicks) if position == 0 => i64::from(clicks) / 100,
+            RotationDirection::Left(clicks) if i64::from(clicks) >= position => {
+                (i64::from(clicks) - position) / 100 + 1
             }
+            RotationDirection::Left(_) => 0,
         };
-
-        if result.position == 0 && (temp == 0 || temp % 100 != 0) {
-            Dial {
-                position: result.position,
-                times_passed_zero: result.times_passed_zero + 1,
-            }
-        } else {
-            result
+        let position = match *direction {
+            RotationDirection::Left(clicks) => position - i64::from(clicks),
+            RotationDirection::Right(clicks) => position + i64::from(clicks),
+        };
+        Dial {
+            position: position.rem_euclid(100) as i32,
+            times_passed_zero: times_passed_zero as u32,
         }
     }
 
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
     pub fn is_zero(&self) -> bool {
         self.position == 0
     }
 
-    pub fn times_passed_zero(&self) -> i32 {
+    pub fn times_passed_zero(&self) -> u32 {
         self.times_passed_zero
     }
 }
 
+/// Rows from the centre of a drawn dial to its rim. It is twice as many columns wide, as cells
+/// are about twice as high as they are wide.
+const RADIUS: usize = 8;
+
+/// The dial as a ring of positions with zero at the top, the position it points at marked `#`
+/// and how often the last rotation passed zero below it.
+impl Render for Dial {
+    fn size(&self) -> (usize, usize) {
+        (4 * RADIUS + 1, 2 * RADIUS + 2)
+    }
+
+    fn render(&self, canvas: &mut dyn Canvas) {
+        let cell = |position: i32| {
+            // Turning right turns the dial clockwise.
+            let angle = f64::from(position) / 100.0 * TAU;
+            let x = (2 * RADIUS) as f64 * (1.0 + angle.sin());
+            let y = RADIUS as f64 * (1.0 - angle.cos());
+            (x.round() as usize, y.round() as usize)
+        };
+        for position in 0..100 {
+            let (x, y) = cell(position);
+            canvas.cell(x, y, '.', Style::Dim);
+        }
+        let (x, y) = cell(0);
+        canvas.cell(x, y, '0', Style::Normal);
+        let (x, y) = cell(self.position);
+        canvas.cell(x, y, '#', Style::Highlight);
+        let label = self.position.to_string();
+        canvas.text(
+            2 * RADIUS - label.len() / 2,
+            RADIUS,
+            &label,
+            Style::Highlight,
+        );
+        let caption = format!("passed zero {} times", self.times_passed_zero);
+        canvas.text(0, 2 * RADIUS + 1, &caption, Style::Normal);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::day1::dial::Dial;
     use crate::day1::rotation_direction::RotationDirection;
+    use crate::render::Ascii;
 
     #[test]
     fn rotating_left_1_from_0_results_in_99() {
@@ -135,4 +170,32 @@ mod tests {
         };
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn rotating_the_largest_amount_does_not_overflow() {
+        let result = Dial::new(99).rotate(&RotationDirection::Right(u32::MAX));
+        assert_eq!(94, result.position());
+        assert_eq!(42949673, result.times_passed_zero());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let dial = Dial::new(50).rotate(&RotationDirection::Left(168));
+        let json = serde_json::to_string(&dial).unwrap();
+        assert_eq!(r#"{"position":82,"times_passed_zero":2}"#, json);
+        assert_eq!(dial, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn renders_pointer_on_the_ring() {
+        let picture = Ascii::render(&Dial::new(50).rotate(&RotationDirection::Right(75)));
+        let lines: Vec<&str> = picture.lines().collect();
+        assert_eq!(18, lines.len());
+        // Position 25 is on the right, level with the centre.
+        assert!(lines[8].ends_with('#'));
+        assert!(lines[8].contains(" 25 "));
+        assert!(lines[0].contains('0'));
+        assert_eq!("passed zero 1 times", lines[17]);
+    }
 }
@@ -0,0 +1,29 @@
+use crate::error::ParseErrorKind;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+
+pub type ParseError = crate::error::ParseError<ErrorKind>;
+pub type ReadError = crate::error::ReadError<ErrorKind>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ErrorKind {
+    MissingDirection,
+    UnknownDirection(char),
+    InvalidAmount(ParseIntError),
+}
+
+impl ParseErrorKind for ErrorKind {
+    const DAY: u8 = 1;
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MissingDirection => write!(f, "missing rotation"),
+            ErrorKind::UnknownDirection(direction) => {
+                write!(f, "unknown direction '{direction}', expected 'L' or 'R'")
+            }
+            ErrorKind::InvalidAmount(error) => write!(f, "invalid rotation amount, {error}"),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::day1::Day1;
+use crate::generate::{Generate, Rng};
+use std::fmt::Write;
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub rotations: usize,
+    /// Most clicks a rotation turns the dial, more than a full turn above 99. Zero counts as one,
+    /// as every rotation turns the dial.
+    pub max_amount: u64,
+}
+
+impl Generate for Day1 {
+    type Params = Params;
+
+    fn params(size: usize) -> Params {
+        Params {
+            rotations: size,
+            max_amount: 999,
+        }
+    }
+
+    fn generate(params: &Params, rng: &mut Rng) -> String {
+        let max_amount = params.max_amount.max(1);
+        let mut input = String::new();
+        for _ in 0..params.rotations {
+            let direction = rng.pick(&['L', 'R']);
+            let amount = rng.range(1..=max_amount);
+            let _ = writeln!(input, "{direction}{amount}");
+        }
+        input
+    }
+}
@@ -1,41 +1,173 @@
-use crate::day1::dial::Dial;
-use crate::day1::rotation_direction::RotationDirection;
+//! Day 1: a dial numbered 0 to 99 is turned left and right by a list of rotations.
+//!
+//! ```
+//! use advent_of_code_2025::day1::{Dial, RotationDirection, find_rotations_where_zero, parse_input};
+//!
+//! let rotations = parse_input("L68\nL30\nR48\nL5\nR60")?;
+//! assert_eq!(1, find_rotations_where_zero(&rotations));
+//!
+//! let dial = Dial::new(50).rotate(&RotationDirection::Left(68));
+//! assert_eq!(82, dial.position());
+//! assert_eq!(1, dial.times_passed_zero());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::render::Render;
+use crate::solution::{Snapshot, Solution};
+use crate::trace::{Event, NoTrace, Tracer};
 use std::error::Error;
+use std::io::BufRead;
 use std::str::FromStr;
 
 mod dial;
+mod error;
+mod generate;
+#[cfg(test)]
+mod reference;
 mod rotation_direction;
 
-pub fn parse_input(input: &str) -> Result<Vec<RotationDirection>, Box<dyn Error>> {
+pub use dial::Dial;
+pub use error::{ErrorKind, ParseError, ReadError};
+pub use generate::Params;
+pub use rotation_direction::RotationDirection;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<RotationDirection>;
+    type PartOne = usize;
+    type PartTwo = u32;
+    type ErrorKind = ErrorKind;
+
+    const EXAMPLE: &'static str = include_str!("test.txt");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn read(reader: impl BufRead) -> Result<Self::Input, ReadError> {
+        read_rotations(reader).collect()
+    }
+
+    fn part_one(rotations: &Self::Input) -> Result<Self::PartOne, Box<dyn Error>> {
+        Ok(find_rotations_where_zero(rotations))
+    }
+
+    fn part_two(rotations: &Self::Input) -> Result<Self::PartTwo, Box<dyn Error>> {
+        find_rotations_where_zer0_has_passed(rotations)
+            .ok_or("dial passed zero more times than fit in 32 bits".into())
+    }
+
+    fn render(rotations: Self::Input, step: Option<usize>) -> Option<Box<dyn Render>> {
+        let dial = rotations
+            .iter()
+            .take(step.unwrap_or(rotations.len()))
+            .fold(Dial::new(50), |dial, rotation| dial.rotate(rotation));
+        Some(Box::new(dial))
+    }
+
+    fn snapshots(rotations: Self::Input) -> Option<Vec<Snapshot>> {
+        Some(dial_snapshots(&rotations))
+    }
+
+    fn trace(
+        rotations: Self::Input,
+        mut tracer: &mut dyn Tracer,
+    ) -> Option<Result<(), Box<dyn Error>>> {
+        turn_dial(&rotations, &mut tracer);
+        Some(Ok(()))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<RotationDirection>, ParseError> {
     let input = input
         .lines()
-        .map(RotationDirection::from_str)
+        .enumerate()
+        .map(|(i, line)| RotationDirection::from_str(line).map_err(|error| error.at_line(i + 1)))
         .collect::<Result<Vec<RotationDirection>, _>>()?;
     Ok(input)
 }
 
-pub fn find_rotations_where_zero(rotations: &[RotationDirection]) -> usize {
-    let mut poisitions: Vec<Dial> = vec![Dial::new(50)];
+/// The rotations of an input, read and parsed one line at a time.
+///
+/// ```
+/// use advent_of_code_2025::day1::{Dial, read_rotations};
+///
+/// // Count the rotations ending at zero without keeping them in memory.
+/// let mut dial = Dial::new(50);
+/// let mut zeros = 0;
+/// for rotation in read_rotations("L68\nL30\nR48".as_bytes()) {
+///     dial = dial.rotate(&rotation?);
+///     zeros += usize::from(dial.is_zero());
+/// }
+/// assert_eq!(1, zeros);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn read_rotations(
+    reader: impl BufRead,
+) -> impl Iterator<Item = Result<RotationDirection, ReadError>> {
+    reader.lines().enumerate().map(|(i, line)| {
+        let rotation = RotationDirection::from_str(&line?).map_err(|error| error.at_line(i + 1))?;
+        Ok(rotation)
+    })
+}
 
+/// The dial starting at 50 and after every rotation, reporting each rotation to `tracer`.
+pub fn turn_dial(rotations: &[RotationDirection], tracer: &mut impl Tracer) -> Vec<Dial> {
+    let mut positions: Vec<Dial> = vec![Dial::new(50)];
     for rotation in rotations {
-        if let Some(previous) = poisitions.last() {
-            let rotation = previous.rotate(rotation);
-            poisitions.push(rotation);
+        if let Some(previous) = positions.last() {
+            let dial = previous.rotate(rotation);
+            tracer.record(|| {
+                Event::new("rotate")
+                    .subject(rotation.to_string())
+                    .field("position", dial.position())
+                    .field("passed_zero", dial.times_passed_zero())
+            });
+            positions.push(dial);
         }
     }
+    positions
+}
 
-    poisitions.into_iter().filter(Dial::is_zero).count()
+pub fn find_rotations_where_zero(rotations: &[RotationDirection]) -> usize {
+    turn_dial(rotations, &mut NoTrace)
+        .into_iter()
+        .filter(Dial::is_zero)
+        .count()
 }
 
-pub fn find_rotations_where_zer0_has_passed(rotations: &[RotationDirection]) -> i32 {
-    let mut poisitions: Vec<Dial> = vec![Dial::new(50)];
+/// The number of times the dial passed zero, `None` if that does not fit in a `u32`.
+pub fn find_rotations_where_zer0_has_passed(rotations: &[RotationDirection]) -> Option<u32> {
+    turn_dial(rotations, &mut NoTrace)
+        .iter()
+        .try_fold(0u32, |total, dial| {
+            total.checked_add(dial.times_passed_zero())
+        })
+}
+
+/// The dial before and after every rotation, counting the rotations ending at zero and the
+/// times zero was passed.
+fn dial_snapshots(rotations: &[RotationDirection]) -> Vec<Snapshot> {
+    let counters = |at_zero, passed_zero| vec![("at zero", at_zero), ("passed zero", passed_zero)];
+    let mut dial = Dial::new(50);
+    let mut snapshots = vec![Snapshot {
+        event: String::from("start"),
+        state: Box::new(dial.clone()),
+        counters: counters(0, 0),
+    }];
+    let (mut at_zero, mut passed_zero) = (0, 0);
     for rotation in rotations {
-        if let Some(previous) = poisitions.last() {
-            let rotation = previous.rotate(rotation);
-            poisitions.push(rotation);
-        }
+        dial = dial.rotate(rotation);
+        at_zero += u64::from(dial.is_zero());
+        passed_zero += u64::from(dial.times_passed_zero());
+        snapshots.push(Snapshot {
+            event: rotation.to_string(),
+            state: Box::new(dial.clone()),
+            counters: counters(at_zero, passed_zero),
+        });
     }
-    poisitions.iter().map(Dial::times_passed_zero).sum()
+    snapshots
 }
 
 #[cfg(test)]
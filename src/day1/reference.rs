@@ -0,0 +1,74 @@
+//! Naive reference implementations to test the solutions against.
+
+use crate::day1::rotation_direction::RotationDirection;
+
+/// Turn the dial one click at a time, returning where it ends up and how many clicks landed on
+/// zero.
+pub fn rotate_click_by_click(position: i32, rotation: &RotationDirection) -> (i32, u32) {
+    let (step, clicks) = match rotation {
+        RotationDirection::Left(clicks) => (-1, *clicks),
+        RotationDirection::Right(clicks) => (1, *clicks),
+    };
+    let mut position = position;
+    let mut zeros = 0;
+    for _ in 0..clicks {
+        position = (position + step).rem_euclid(100);
+        if position == 0 {
+            zeros += 1;
+        }
+    }
+    (position, zeros)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day1::reference::rotate_click_by_click;
+    use crate::day1::{Day1, Dial, RotationDirection};
+    use crate::differential::{Config, compare, shrink_number, shrink_vec};
+    use crate::generate::Generate;
+    use crate::solution::Solution;
+    use std::error::Error;
+
+    fn shrink_rotation(rotation: &RotationDirection) -> Vec<RotationDirection> {
+        match *rotation {
+            RotationDirection::Left(clicks) => shrink_number(u64::from(clicks), 0)
+                .into_iter()
+                .map(|clicks| RotationDirection::Left(clicks as u32))
+                .chain([RotationDirection::Right(clicks)])
+                .collect(),
+            RotationDirection::Right(clicks) => shrink_number(u64::from(clicks), 0)
+                .into_iter()
+                .map(|clicks| RotationDirection::Right(clicks as u32))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn dial_rotates_like_clicking_through_every_position() -> Result<(), Box<dyn Error>> {
+        compare(
+            &Config::default(),
+            |rng| Day1::parse(&Day1::generate(&Day1::params(10), rng)).unwrap(),
+            |rotations| {
+                let mut dial = Dial::new(50);
+                let mut positions = Vec::new();
+                for rotation in rotations {
+                    dial = dial.rotate(rotation);
+                    positions.push((dial.position(), dial.times_passed_zero()));
+                }
+                positions
+            },
+            |rotations| {
+                let mut position = 50;
+                let mut positions = Vec::new();
+                for rotation in rotations {
+                    let (next, zeros) = rotate_click_by_click(position, rotation);
+                    position = next;
+                    positions.push((position, zeros));
+                }
+                positions
+            },
+            |rotations| shrink_vec(rotations, shrink_rotation),
+        )?;
+        Ok(())
+    }
+}
@@ -1,30 +1,53 @@
-use std::error::Error;
+use crate::day1::error::{ErrorKind, ParseError};
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RotationDirection {
-    Right(i32),
-    Left(i32),
+    Right(u32),
+    Left(u32),
 }
 
 impl FromStr for RotationDirection {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let first = s.chars().nth(0);
-        let rest = s.chars().skip(1);
-        let amount: i32 = rest.collect::<String>().parse()?;
+        let mut chars = s.chars();
+        let first = chars.next();
+        let rest = chars.as_str();
+        let amount = || {
+            rest.parse().map_err(|error| {
+                ParseError::new(ErrorKind::InvalidAmount(error), rest).shift_columns(1)
+            })
+        };
         match first {
-            Some('L') => Ok(RotationDirection::Left(amount)),
-            Some('R') => Ok(RotationDirection::Right(amount)),
-            None | Some(_) => Err("Unknown direction".into()),
+            Some('L') => Ok(RotationDirection::Left(amount()?)),
+            Some('R') => Ok(RotationDirection::Right(amount()?)),
+            Some(direction) => Err(ParseError::new(
+                ErrorKind::UnknownDirection(direction),
+                direction,
+            )),
+            None => Err(ParseError::new(ErrorKind::MissingDirection, s)),
+        }
+    }
+}
+
+impl Display for RotationDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RotationDirection::Left(amount) => write!(f, "L{amount}"),
+            RotationDirection::Right(amount) => write!(f, "R{amount}"),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::day1::error::ErrorKind;
     use crate::day1::rotation_direction::RotationDirection;
+    use crate::differential::{self, Config, shrink_number};
+    use std::error::Error;
 
     #[test]
     fn test_direction_parsing() {
@@ -32,4 +55,59 @@ mod tests {
         let result: RotationDirection = input.parse().unwrap();
         assert_eq!(RotationDirection::Left(32), result);
     }
+
+    #[test]
+    fn unknown_direction_points_at_direction() {
+        let error = "X32".parse::<RotationDirection>().unwrap_err();
+        assert_eq!(&ErrorKind::UnknownDirection('X'), error.kind());
+        assert_eq!(1, error.column());
+        assert_eq!("X", error.text());
+    }
+
+    #[test]
+    fn invalid_amount_points_at_amount() {
+        let error = "R3x".parse::<RotationDirection>().unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::InvalidAmount(_)));
+        assert_eq!(2, error.column());
+        assert_eq!("3x", error.text());
+    }
+
+    #[test]
+    fn negative_amount_is_an_error() {
+        let error = "R-5".parse::<RotationDirection>().unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::InvalidAmount(_)));
+        assert_eq!(2, error.column());
+        assert_eq!("-5", error.text());
+    }
+
+    #[test]
+    fn displays_as_parsed() -> Result<(), Box<dyn Error>> {
+        differential::round_trip(
+            &Config::default(),
+            |rng| {
+                let amount = rng.range(0..=u64::from(u32::MAX)) as u32;
+                match rng.chance(0.5) {
+                    true => RotationDirection::Left(amount),
+                    false => RotationDirection::Right(amount),
+                }
+            },
+            |rotation| match rotation {
+                RotationDirection::Left(amount) => shrink_number(u64::from(*amount), 0)
+                    .into_iter()
+                    .map(|amount| RotationDirection::Left(amount as u32))
+                    .collect(),
+                RotationDirection::Right(amount) => vec![RotationDirection::Left(*amount)],
+            },
+        )?;
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let rotations = vec![RotationDirection::Left(68), RotationDirection::Right(0)];
+        let json = serde_json::to_string(&rotations).unwrap();
+        assert_eq!(r#"[{"Left":68},{"Right":0}]"#, json);
+        assert_eq!(rotations, serde_json::from_str::<Vec<_>>(&json).unwrap());
+    }
 }
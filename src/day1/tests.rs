@@ -1,38 +1,44 @@
-use crate::day1::{find_rotations_where_zer0_has_passed, find_rotations_where_zero, parse_input};
-use std::error::Error;
+use crate::day1::{Day1, ErrorKind, Params, ReadError, parse_input};
+use crate::generate::{Generate, Rng, assert_read_matches_parse};
+use crate::solution::Solution;
 
 #[test]
-fn day1_part1_example() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("test.txt");
-    let rotations = parse_input(input)?;
-    let result = find_rotations_where_zero(&rotations);
-    assert_eq!(3, result);
-    Ok(())
+fn day1_parse_error_reports_line() {
+    let error = parse_input("L68\nL30\nB48").unwrap_err();
+    assert_eq!(&ErrorKind::UnknownDirection('B'), error.kind());
+    assert_eq!(1, error.day());
+    assert_eq!(3, error.line());
+    assert_eq!(1, error.column());
 }
 
 #[test]
-fn day1_part1_input() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("input.txt");
-    let rotations = parse_input(input)?;
-    let result = find_rotations_where_zero(&rotations);
-    assert_eq!(1036, result);
-    Ok(())
+fn day1_too_many_zero_passes_is_an_error() {
+    let rotations = parse_input(&"R4294967295\n".repeat(101)).unwrap();
+    assert!(Day1::part_two(&rotations).is_err());
 }
 
 #[test]
-fn day1_part2_example() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("test.txt");
-    let rotations = parse_input(input)?;
-    let result = find_rotations_where_zer0_has_passed(&rotations);
-    assert_eq!(6, result);
-    Ok(())
+fn day1_read_matches_parse() {
+    assert_read_matches_parse::<Day1>(&[]);
 }
 
 #[test]
-fn day1_part2_input() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("input.txt");
-    let rotations = parse_input(input)?;
-    let result = find_rotations_where_zer0_has_passed(&rotations);
-    assert_eq!(2124, result);
-    Ok(())
+fn day1_read_reports_errors() {
+    let error = Day1::read("L68\nB48\n".as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Parse(error) if error.line() == 2));
+    let error = Day1::read(&b"L68\n\xff\n"[..]).unwrap_err();
+    assert!(matches!(error, ReadError::Io(_)));
+}
+
+#[test]
+fn day1_generates_rotations_of_one_click_for_zero_max_amount() {
+    let params = Params {
+        max_amount: 0,
+        ..Day1::params(5)
+    };
+    let input = Day1::generate(&params, &mut Rng::new(0));
+    assert!(
+        input.lines().all(|line| line == "L1" || line == "R1"),
+        "{input}"
+    );
 }
@@ -1,23 +1,85 @@
+use crate::day2::error::{ParseError, ReadError};
 use crate::day2::product_id::{ProductId, ProductIdRange};
-use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
 use std::str::FromStr;
 
+#[derive(Debug, PartialEq, Clone)]
 pub struct Database {
     product_id_ranges: Vec<ProductIdRange>,
 }
 
 impl FromStr for Database {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let product_id_ranges: Vec<ProductIdRange> = s
-            .split(',')
-            .map(ProductIdRange::from_str)
-            .collect::<Result<_, _>>()?;
+        let mut product_id_ranges: Vec<ProductIdRange> = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let mut column = 0;
+            for range in line.split(',') {
+                if !range.trim().is_empty() {
+                    let range = ProductIdRange::from_str(range)
+                        .map_err(|error| error.at_line(i + 1).shift_columns(column))?;
+                    product_id_ranges.push(range);
+                }
+                column += range.chars().count() + 1;
+            }
+        }
         Ok(Database { product_id_ranges })
     }
 }
 
+/// The ranges of an input, read and parsed one range at a time, so even an input of a single
+/// line is never held in memory as a whole.
+pub fn read_product_id_ranges(
+    reader: impl BufRead,
+) -> impl Iterator<Item = Result<ProductIdRange, ReadError>> {
+    let (mut line, mut column) = (1, 0);
+    reader.split(b',').flat_map(move |chunk| {
+        let chunk = match chunk.and_then(|chunk| {
+            String::from_utf8(chunk)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+        }) {
+            Ok(chunk) => chunk,
+            Err(error) => return vec![Err(error.into())],
+        };
+        // Ranges are separated by newlines as well as by commas.
+        let mut ranges = Vec::new();
+        for (i, range) in chunk.split('\n').enumerate() {
+            if i > 0 {
+                line += 1;
+                column = 0;
+            }
+            if !range.trim().is_empty() {
+                let range = ProductIdRange::from_str(range)
+                    .map_err(|error| error.at_line(line).shift_columns(column).into());
+                ranges.push(range);
+            }
+            column += range.chars().count();
+        }
+        column += 1;
+        ranges
+    })
+}
+
+/// The ranges on a single line separated by commas, like the puzzle input.
+impl Display for Database {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, range) in self.product_id_ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{range}")?;
+        }
+        writeln!(f)
+    }
+}
+
 impl Database {
+    pub fn new(product_id_ranges: Vec<ProductIdRange>) -> Self {
+        Database { product_id_ranges }
+    }
+
+    /// Ids made of a sequence of digits repeated exactly twice.
     pub fn find_naive_invalid_product_ids(&self) -> Vec<ProductId> {
         self.product_id_ranges
             .iter()
@@ -25,6 +87,7 @@ impl Database {
             .collect()
     }
 
+    /// Ids made of a sequence of digits repeated at least twice.
     pub fn find_invalid_product_ids(&self) -> Vec<ProductId> {
         self.product_id_ranges
             .iter()
@@ -32,3 +95,43 @@ impl Database {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::day2::database::Database;
+    use crate::day2::product_id::ProductIdRange;
+    use crate::differential::{self, Config, shrink_vec};
+    use std::error::Error;
+
+    #[test]
+    fn displays_as_parsed() -> Result<(), Box<dyn Error>> {
+        differential::round_trip(
+            &Config::default(),
+            |rng| {
+                let ranges = (0..rng.range(0..=10))
+                    .map(|_| {
+                        let from = rng.range(0..=u64::MAX);
+                        ProductIdRange::new(from, rng.range(from..=u64::MAX))
+                    })
+                    .collect();
+                Database::new(ranges)
+            },
+            |database| {
+                shrink_vec(&database.product_id_ranges, |_| Vec::new())
+                    .into_iter()
+                    .map(Database::new)
+                    .collect()
+            },
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn displays_ranges_on_one_line() {
+        let database = Database::new(vec![
+            ProductIdRange::new(11, 22),
+            ProductIdRange::new(95, 115),
+        ]);
+        assert_eq!("11-22,95-115\n", database.to_string());
+    }
+}
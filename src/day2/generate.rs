@@ -0,0 +1,37 @@
+use crate::day2::Day2;
+use crate::generate::{Generate, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub ranges: usize,
+    /// Most digits of the first id of a range, from 1 to 19, as larger ids do not fit in a `u64`.
+    /// Values outside that are clamped to it.
+    pub max_digits: u32,
+    /// Most ids in a range besides the first, every id of a range is checked.
+    pub max_span: u64,
+}
+
+impl Generate for Day2 {
+    type Params = Params;
+
+    fn params(size: usize) -> Params {
+        Params {
+            ranges: size,
+            max_digits: 10,
+            max_span: 1000,
+        }
+    }
+
+    fn generate(params: &Params, rng: &mut Rng) -> String {
+        let max_digits = params.max_digits.clamp(1, 19);
+        let ranges: Vec<String> = (0..params.ranges)
+            .map(|_| {
+                let digits = rng.range(1..=u64::from(max_digits)) as u32;
+                let from = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+                let to = from.saturating_add(rng.range(0..=params.max_span));
+                format!("{from}-{to}")
+            })
+            .collect();
+        ranges.join(",") + "\n"
+    }
+}
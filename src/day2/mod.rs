@@ -1,5 +1,61 @@
+//! Day 2: product id ranges containing ids made of a repeated sequence of digits.
+//!
+//! ```
+//! use advent_of_code_2025::day2::{Database, ProductId, ProductIdRange};
+//!
+//! let database: Database = "11-22,95-115".parse()?;
+//! let invalid: Vec<u64> = database.find_naive_invalid_product_ids().iter().map(u64::from).collect();
+//! assert_eq!(vec![11, 22, 99], invalid);
+//!
+//! let range = ProductIdRange::new(110, 112);
+//! assert_eq!(1, range.into_iter().filter(ProductId::is_invalid).count());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::solution::Solution;
+use std::error::Error;
+use std::io::BufRead;
+
 mod database;
+mod error;
+mod generate;
 mod product_id;
 
+pub use database::{Database, read_product_id_ranges};
+pub use error::{ErrorKind, ParseError, ReadError};
+pub use generate::Params;
+pub use product_id::{ProductId, ProductIdIterator, ProductIdRange};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Database;
+    type PartOne = u64;
+    type PartTwo = u64;
+    type ErrorKind = ErrorKind;
+
+    const EXAMPLE: &'static str = include_str!("test.txt");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn read(reader: impl BufRead) -> Result<Self::Input, ReadError> {
+        read_product_id_ranges(reader)
+            .collect::<Result<_, _>>()
+            .map(Database::new)
+    }
+
+    fn part_one(database: &Self::Input) -> Result<Self::PartOne, Box<dyn Error>> {
+        let invalid_ids = database.find_naive_invalid_product_ids();
+        Ok(invalid_ids.iter().map(u64::from).sum())
+    }
+
+    fn part_two(database: &Self::Input) -> Result<Self::PartTwo, Box<dyn Error>> {
+        let invalid_ids = database.find_invalid_product_ids();
+        Ok(invalid_ids.iter().map(u64::from).sum())
+    }
+}
+
 #[cfg(test)]
 mod tests;
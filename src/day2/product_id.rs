@@ -1,4 +1,8 @@
-use std::error::Error;
+use crate::day2::error::{ErrorKind, ParseError};
+use crate::error::leading_whitespace;
+use std::cmp::{max, min};
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
 use std::str::FromStr;
 
 #[derive(Debug, PartialEq)]
@@ -31,6 +35,12 @@ impl ProductId {
     }
 }
 
+impl From<u64> for ProductId {
+    fn from(value: u64) -> Self {
+        ProductId(value)
+    }
+}
+
 impl From<ProductId> for u64 {
     fn from(value: ProductId) -> Self {
         value.0
@@ -43,30 +53,71 @@ impl From<&ProductId> for u64 {
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// Serialised like a range of the input, so that reversed ranges are rejected when deserialised.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
 pub struct ProductIdRange {
     from: u64,
     to: u64,
 }
 
 impl ProductIdRange {
+    /// The ids from `from` to `to` inclusive, swapped if `to` is below `from`.
     pub fn new(from: u64, to: u64) -> Self {
-        ProductIdRange { from, to }
+        ProductIdRange {
+            from: min(from, to),
+            to: max(from, to),
+        }
     }
 }
 
 impl FromStr for ProductIdRange {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let split = s.trim().split_once('-');
-        if let Some((from, to)) = split {
+        let indent = leading_whitespace(s);
+        let trimmed = s.trim();
+        let parse = |id: &str, column: usize| {
+            id.parse().map_err(|error| {
+                ParseError::new(ErrorKind::InvalidProductId(error), id).shift_columns(column)
+            })
+        };
+        if let Some((from, to)) = trimmed.split_once('-') {
             let product_id_range = ProductIdRange {
-                from: from.parse()?,
-                to: to.parse()?,
+                from: parse(from, indent)?,
+                to: parse(to, indent + from.chars().count() + 1)?,
             };
+            if product_id_range.to < product_id_range.from {
+                return Err(
+                    ParseError::new(ErrorKind::ReversedRange, trimmed).shift_columns(indent)
+                );
+            }
             return Ok(product_id_range);
         };
-        Err("Error parsing ProductIdRange".into())
+        Err(ParseError::new(ErrorKind::MissingSeparator, trimmed).shift_columns(indent))
+    }
+}
+
+impl Display for ProductIdRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.from, self.to)
+    }
+}
+
+impl TryFrom<String> for ProductIdRange {
+    type Error = ParseError;
+
+    fn try_from(range: String) -> Result<Self, Self::Error> {
+        range.parse()
+    }
+}
+
+impl From<ProductIdRange> for String {
+    fn from(range: ProductIdRange) -> Self {
+        range.to_string()
     }
 }
 
@@ -76,32 +127,28 @@ impl IntoIterator for &ProductIdRange {
 
     fn into_iter(self) -> Self::IntoIter {
         ProductIdIterator {
-            current: self.from,
-            to: self.to,
+            ids: self.from..=self.to,
         }
     }
 }
 
+#[derive(Debug)]
 pub struct ProductIdIterator {
-    current: u64,
-    to: u64,
+    ids: RangeInclusive<u64>,
 }
 
 impl Iterator for ProductIdIterator {
     type Item = ProductId;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > self.to {
-            return None;
-        }
-        let next = self.current;
-        self.current += 1;
-        Some(ProductId(next))
+        self.ids.next().map(ProductId)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::day2::error::ErrorKind;
     use crate::day2::product_id::{ProductId, ProductIdRange};
+    use crate::differential::{self, Config, shrink_number};
     use std::error::Error;
 
     #[test]
@@ -127,6 +174,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn invalid_product_id_points_at_id() {
+        let error = " 11-2x".parse::<ProductIdRange>().unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::InvalidProductId(_)));
+        assert_eq!(5, error.column());
+        assert_eq!("2x", error.text());
+    }
+
+    #[test]
+    fn reversed_range_is_an_error() {
+        let error = "22-11".parse::<ProductIdRange>().unwrap_err();
+        assert_eq!(&ErrorKind::ReversedRange, error.kind());
+        assert_eq!("22-11", error.text());
+    }
+
+    #[test]
+    fn new_orders_reversed_ends() -> Result<(), Box<dyn Error>> {
+        let range = ProductIdRange::new(15, 12);
+        assert_eq!(ProductIdRange::new(12, 15), range);
+        assert_eq!(4, range.into_iter().count());
+        assert_eq!(range, range.to_string().parse()?);
+        Ok(())
+    }
+
     #[test]
     fn product_id_iterator_returns_to_and_from_iterator() {
         let product_id_range = ProductIdRange::new(12, 15);
@@ -135,6 +206,12 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn product_id_iterator_ends_at_largest_id() {
+        let range = ProductIdRange::new(u64::MAX - 1, u64::MAX);
+        assert_eq!(2, range.into_iter().count());
+    }
+
     #[test]
     fn test_111_is_invalid() {
         assert!(ProductId(111).is_invalid());
@@ -145,4 +222,35 @@ mod tests {
         let size: usize = 3;
         assert!(size.is_multiple_of(1));
     }
+
+    #[test]
+    fn range_displays_as_parsed() -> Result<(), Box<dyn Error>> {
+        differential::round_trip(
+            &Config::default(),
+            |rng| {
+                let from = rng.range(0..=u64::MAX);
+                ProductIdRange::new(from, rng.range(from..=u64::MAX))
+            },
+            |range| {
+                let to = shrink_number(range.to, range.from)
+                    .into_iter()
+                    .map(|to| ProductIdRange::new(range.from, to));
+                let from = shrink_number(range.from, 0)
+                    .into_iter()
+                    .map(|from| ProductIdRange::new(from, range.to));
+                to.chain(from).collect()
+            },
+        )?;
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn range_serde_round_trip() {
+        let range = ProductIdRange::new(11, u64::MAX);
+        let json = serde_json::to_string(&range).unwrap();
+        assert_eq!(r#""11-18446744073709551615""#, json);
+        assert_eq!(range, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<ProductIdRange>(r#""22-11""#).is_err());
+    }
 }
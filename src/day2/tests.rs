@@ -1,42 +1,45 @@
 use crate::day2::database::Database;
-use std::error::Error;
+use crate::day2::{Day2, ErrorKind, Params, ReadError};
+use crate::generate::{Generate, Rng, assert_read_matches_parse};
+use crate::solution::Solution;
 
 #[test]
-fn day2_part1_example() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("test.txt");
-    let database: Database = input.parse()?;
-    let invalid_ids = database.find_naive_invalid_product_ids();
-    let result: u64 = invalid_ids.iter().map(u64::from).sum();
-    assert_eq!(1227775554, result);
-    Ok(())
+fn day2_parse_error_reports_column() {
+    let error = "11-22,95115,998-1012".parse::<Database>().unwrap_err();
+    assert_eq!(&ErrorKind::MissingSeparator, error.kind());
+    assert_eq!(1, error.line());
+    assert_eq!(7, error.column());
+    assert_eq!("95115", error.text());
 }
 
 #[test]
-fn day2_part1_solution() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("input.txt");
-    let database: Database = input.parse()?;
-    let invalid_ids = database.find_naive_invalid_product_ids();
-    let result: u64 = invalid_ids.iter().map(u64::from).sum();
-    assert_eq!(30323879646, result);
-    Ok(())
+fn day2_read_matches_parse() {
+    let split_over_lines = "11-22,95-115
+998-1012,
+1188511880-1188511890
+";
+    assert_read_matches_parse::<Day2>(&[split_over_lines]);
 }
 
 #[test]
-fn day2_part2_example() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("test.txt");
-    let database: Database = input.parse()?;
-    let invalid_ids = database.find_invalid_product_ids();
-    let result: u64 = invalid_ids.iter().map(u64::from).sum();
-    assert_eq!(4174379265, result);
-    Ok(())
+fn day2_read_reports_column() {
+    let error = Day2::read("11-22,\n998-1012, 95115".as_bytes()).unwrap_err();
+    let ReadError::Parse(error) = error else {
+        panic!("expected a parse error, got {error}");
+    };
+    assert_eq!(&ErrorKind::MissingSeparator, error.kind());
+    assert_eq!(2, error.line());
+    assert_eq!(11, error.column());
 }
 
 #[test]
-fn day2_part2_solution() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("input.txt");
-    let database: Database = input.parse()?;
-    let invalid_ids = database.find_invalid_product_ids();
-    let result: u64 = invalid_ids.iter().map(u64::from).sum();
-    assert_eq!(43872163557, result);
-    Ok(())
+fn day2_generates_ids_for_out_of_range_max_digits() {
+    for max_digits in [0, 20] {
+        let params = Params {
+            max_digits,
+            ..Day2::params(5)
+        };
+        let input = Day2::generate(&params, &mut Rng::new(0));
+        assert!(Day2::parse(&input).is_ok(), "{input}");
+    }
 }
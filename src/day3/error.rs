@@ -0,0 +1,24 @@
+use crate::error::ParseErrorKind;
+use std::fmt::{Display, Formatter};
+
+pub type ParseError = crate::error::ParseError<ErrorKind>;
+pub type ReadError = crate::error::ReadError<ErrorKind>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ErrorKind {
+    InvalidBattery(char),
+}
+
+impl ParseErrorKind for ErrorKind {
+    const DAY: u8 = 3;
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::InvalidBattery(battery) => {
+                write!(f, "invalid battery '{battery}', expected a digit")
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::day3::Day3;
+use crate::generate::{Generate, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub banks: usize,
+    /// Batteries in every bank, part two switches on twelve.
+    pub batteries: usize,
+}
+
+impl Generate for Day3 {
+    type Params = Params;
+
+    fn params(size: usize) -> Params {
+        Params {
+            banks: size,
+            batteries: 100,
+        }
+    }
+
+    fn generate(params: &Params, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..params.banks {
+            let bank = (0..params.batteries).map(|_| char::from(b'0' + rng.range(1..=9) as u8));
+            input.extend(bank);
+            input.push('\n');
+        }
+        input
+    }
+}
@@ -1,32 +1,105 @@
+//! Day 3: banks of batteries, each a line of digits, and the highest joltage that can be drawn.
+//!
+//! ```
+//! use advent_of_code_2025::day3::{Bank, MonotonicStack};
+//!
+//! let bank: Bank = "818181911112111".parse()?;
+//! assert_eq!(92, bank.find_highest_joltage()?);
+//! assert_eq!(888911112111, bank.find_highest_joltage_v2()?);
+//!
+//! let mut stack = MonotonicStack::new(2, 4);
+//! for value in [1, 3, 2, 4] {
+//!     stack.push(value);
+//! }
+//! assert_eq!(&[3, 4], stack.values());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod error;
+mod generate;
 mod monotonic_stack;
+#[cfg(test)]
+mod reference;
 
-use crate::day3::monotonic_stack::MonotonicStack;
+pub use error::{ErrorKind, ParseError, ReadError};
+pub use generate::Params;
+pub use monotonic_stack::MonotonicStack;
+
+use crate::solution::Solution;
+use crate::trace::{NoTrace, Tracer};
 use std::collections::HashMap;
 use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
 use std::str::FromStr;
 
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Bank>;
+    type PartOne = i32;
+    type PartTwo = u64;
+    type ErrorKind = ErrorKind;
+
+    const EXAMPLE: &'static str = include_str!("test.txt");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Bank::from_str(line).map_err(|error| error.at_line(i + 1)))
+            .collect()
+    }
+
+    fn read(reader: impl BufRead) -> Result<Self::Input, ReadError> {
+        read_banks(reader).collect()
+    }
+
+    fn part_one(banks: &Self::Input) -> Result<Self::PartOne, Box<dyn Error>> {
+        banks.iter().map(Bank::find_highest_joltage).sum()
+    }
+
+    fn part_two(banks: &Self::Input) -> Result<Self::PartTwo, Box<dyn Error>> {
+        banks.iter().map(Bank::find_highest_joltage_v2).sum()
+    }
+
+    fn trace(
+        banks: Self::Input,
+        mut tracer: &mut dyn Tracer,
+    ) -> Option<Result<(), Box<dyn Error>>> {
+        Some(
+            banks
+                .iter()
+                .try_for_each(|bank| bank.find_highest_joltage_v2_traced(&mut tracer).map(drop)),
+        )
+    }
+}
+
+/// The banks of an input, read and parsed one line at a time.
+pub fn read_banks(reader: impl BufRead) -> impl Iterator<Item = Result<Bank, ReadError>> {
+    reader.lines().enumerate().map(|(i, line)| {
+        let bank = Bank::from_str(&line?).map_err(|error| error.at_line(i + 1))?;
+        Ok(bank)
+    })
+}
+
+/// Serialised as its digits, like a line of the input, as the index is derived from them.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
 pub struct Bank {
-    input: String,
     batteries: Vec<char>,
     index: HashMap<char, Vec<usize>>,
 }
 
-impl Bank {}
-
 impl Bank {
-    fn generate_index(battery: &[char]) -> HashMap<char, Vec<usize>> {
+    fn generate_index(batteries: &[char]) -> HashMap<char, Vec<usize>> {
         let mut index: HashMap<char, Vec<usize>> = HashMap::new();
-        for i in 0..10 {
-            let char = i.to_string().chars().nth(0);
-            if let Some(v) = char {
-                index.insert(v, Vec::new());
-            }
-        }
-        for (i, battery) in battery.iter().enumerate() {
-            match index.get_mut(battery) {
-                None => panic!("hashmap not initialized"),
-                Some(vec) => vec.push(i),
-            }
+        for (i, battery) in batteries.iter().enumerate() {
+            index.entry(*battery).or_default().push(i);
         }
         for list in index.values_mut() {
             list.sort();
@@ -36,15 +109,23 @@ impl Bank {
         index
     }
 
-    pub fn new(batteries: &[char]) -> Self {
-        let index = Self::generate_index(&batteries);
-        let input: String = batteries.into_iter().collect();
-        let batteries = batteries.to_owned();
-        Self {
-            batteries,
-            index,
-            input,
+    /// A bank of the given batteries, which must all be digits.
+    pub fn new(batteries: &[char]) -> Result<Self, ParseError> {
+        Self::from_batteries(batteries.to_owned())
+    }
+
+    fn from_batteries(batteries: Vec<char>) -> Result<Self, ParseError> {
+        if let Some((i, battery)) = batteries
+            .iter()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_digit())
+        {
+            return Err(
+                ParseError::new(ErrorKind::InvalidBattery(*battery), *battery).shift_columns(i),
+            );
         }
+        let index = Self::generate_index(&batteries);
+        Ok(Self { batteries, index })
     }
 
     fn joltage_exists(first: &[usize], second: &[usize]) -> bool {
@@ -80,6 +161,7 @@ impl Bank {
         }
     }
 
+    /// The highest two digit joltage that can be made by switching on two batteries.
     pub fn find_highest_joltage(&self) -> Result<i32, Box<dyn Error>> {
         (10..100)
             .rev()
@@ -87,7 +169,17 @@ impl Bank {
             .ok_or(Box::from("No solution found"))
     }
 
+    /// The highest twelve digit joltage that can be made by switching on twelve batteries.
     pub fn find_highest_joltage_v2(&self) -> Result<u64, Box<dyn Error>> {
+        self.find_highest_joltage_v2_traced(&mut NoTrace)
+    }
+
+    /// [`find_highest_joltage_v2`](Self::find_highest_joltage_v2), reporting the pushes and pops
+    /// of the monotonic stack to `tracer`.
+    pub fn find_highest_joltage_v2_traced(
+        &self,
+        tracer: &mut impl Tracer,
+    ) -> Result<u64, Box<dyn Error>> {
         // let mut result: Vec<(usize, char)> = Vec::new();
         // for i in (0..10).rev() {
         //     let char = i.to_string().chars().nth(0).unwrap();
@@ -104,11 +196,18 @@ impl Bank {
         // let u: BigInt = string.parse()?;
         // Ok(u)
 
+        if self.batteries.len() < 12 {
+            return Err(format!(
+                "bank has {} batteries, cannot switch on 12",
+                self.batteries.len()
+            )
+            .into());
+        }
         let mut stack: MonotonicStack<char> = MonotonicStack::new(12, self.batteries.len());
+        let digit = |battery: char| u64::from(battery.to_digit(10).expect("batteries are digits"));
         for char in &self.batteries {
-            stack.push(*char)
+            stack.push_traced(*char, tracer, digit)
         }
-        assert_eq!(stack.values().len(), 12);
         let string: String = stack.values().iter().collect();
         let u: u64 = string.parse()?;
         Ok(u)
@@ -116,17 +215,41 @@ impl Bank {
 }
 
 impl FromStr for Bank {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let chars = s.chars().collect::<Vec<char>>();
-        Ok(Bank::new(&chars))
+        Bank::from_batteries(s.chars().collect())
+    }
+}
+
+impl TryFrom<String> for Bank {
+    type Error = ParseError;
+
+    fn try_from(batteries: String) -> Result<Self, Self::Error> {
+        batteries.parse()
+    }
+}
+
+impl From<Bank> for String {
+    fn from(bank: Bank) -> Self {
+        bank.to_string()
+    }
+}
+
+impl Display for Bank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.batteries
+            .iter()
+            .try_for_each(|battery| write!(f, "{battery}"))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day3::Bank;
+    use crate::day3::{Bank, Day3, ErrorKind, ReadError};
+    use crate::differential::{self, Config, shrink_vec};
+    use crate::generate::assert_read_matches_parse;
+    use crate::solution::Solution;
     use std::error::Error;
     use std::str::FromStr;
 
@@ -138,6 +261,14 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn invalid_battery_reports_position() {
+        let error = Day3::parse("987\n81x9").unwrap_err();
+        assert_eq!(&ErrorKind::InvalidBattery('x'), error.kind());
+        assert_eq!(2, error.line());
+        assert_eq!(3, error.column());
+    }
+
     #[test]
     fn part_2_example_1() -> Result<(), Box<dyn Error>> {
         let bank = Bank::from_str("987654321111111")?;
@@ -175,65 +306,54 @@ mod tests {
     }
 
     #[test]
-    fn day3_part1_example() -> Result<(), Box<dyn Error>> {
-        let input = include_str!("test.txt");
-        let banks = input
-            .lines()
-            .map(Bank::from_str)
-            .collect::<Result<Vec<_>, _>>()?;
-        let sum: i32 = banks
-            .iter()
-            .map(|x1| x1.find_highest_joltage())
-            .sum::<Result<_, _>>()?;
-        assert_eq!(357, sum);
-        Ok(())
+    fn rejects_batteries_that_are_not_digits() {
+        let error = Bank::new(&['9', '8', 'x']).unwrap_err();
+        assert_eq!(&ErrorKind::InvalidBattery('x'), error.kind());
+        assert_eq!(3, error.column());
     }
 
     #[test]
-    fn day3_part1_solution() -> Result<(), Box<dyn Error>> {
-        let input = include_str!("input.txt");
-        let banks = input
-            .lines()
-            .map(Bank::from_str)
-            .collect::<Result<Vec<_>, _>>()?;
-        let sum: i32 = banks
-            .iter()
-            .map(|x1| x1.find_highest_joltage())
-            .sum::<Result<_, _>>()?;
-        assert_eq!(17109, sum);
+    fn too_few_batteries_for_part_2_is_an_error() -> Result<(), Box<dyn Error>> {
+        let bank = Bank::from_str("98765432111")?;
+        assert!(bank.find_highest_joltage_v2().is_err());
         Ok(())
     }
 
     #[test]
-    fn day3_part2_example() -> Result<(), Box<dyn Error>> {
-        let input = include_str!("test.txt");
-        let banks = input
-            .lines()
-            .map(Bank::from_str)
-            .collect::<Result<Vec<_>, _>>()?;
-        let sum = banks
-            .iter()
-            .map(|x1| x1.find_highest_joltage_v2())
-            .sum::<Result<_, _>>()?;
-        let expected: u64 = 3121910778619;
-        assert_eq!(expected, sum);
-        Ok(())
+    fn read_matches_parse() {
+        assert_read_matches_parse::<Day3>(&[]);
+        let error = Day3::read("987\n81x9".as_bytes()).unwrap_err();
+        assert!(matches!(error, ReadError::Parse(error) if error.column() == 3));
     }
 
     #[test]
-    fn day3_part2_solution() -> Result<(), Box<dyn Error>> {
-        let input = include_str!("input.txt");
-        let banks = input
-            .lines()
-            .map(Bank::from_str)
-            .collect::<Result<Vec<_>, _>>()?;
-        let sum = banks
-            .iter()
-            .map(|x1| x1.find_highest_joltage_v2())
-            .sum::<Result<_, _>>()?;
-        let expected: u64 = 96107789497890;
+    fn bank_displays_as_parsed() -> Result<(), Box<dyn Error>> {
+        differential::round_trip(
+            &Config::default(),
+            |rng| {
+                let digits = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+                let batteries: Vec<char> =
+                    (0..rng.range(0..=20)).map(|_| *rng.pick(&digits)).collect();
+                Bank::new(&batteries).unwrap()
+            },
+            |bank| {
+                shrink_vec(&bank.batteries, |_| Vec::new())
+                    .iter()
+                    .map(|batteries| Bank::new(batteries).unwrap())
+                    .collect()
+            },
+        )?;
+        Ok(())
+    }
 
-        assert_eq!(expected, sum);
+    #[cfg(feature = "serde")]
+    #[test]
+    fn bank_serde_round_trip() -> Result<(), Box<dyn Error>> {
+        let bank = Bank::from_str("818181911112111")?;
+        let json = serde_json::to_string(&bank)?;
+        assert_eq!(r#""818181911112111""#, json);
+        assert_eq!(bank, serde_json::from_str(&json)?);
+        assert!(serde_json::from_str::<Bank>(r#""81x""#).is_err());
         Ok(())
     }
 }
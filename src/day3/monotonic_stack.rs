@@ -1,3 +1,8 @@
+use crate::trace::{Event, Tracer};
+
+/// Keeps the lexicographically largest subsequence of `target_capacity` values out of a stream
+/// of `input_size` values. Values pushed beyond `input_size` are only kept while there is space
+/// left.
 #[derive(Debug)]
 pub struct MonotonicStack<T> {
     target_capacity: usize,
@@ -8,33 +13,34 @@ pub struct MonotonicStack<T> {
 
 impl<T: Copy + PartialOrd> MonotonicStack<T> {
     pub fn push(&mut self, value: T) {
-        if self.internal_stack.is_empty() {
-            self.internal_stack.push(value);
-            self.current += 1;
-        } else if self.items_left() <= self.space_left() {
-            self.internal_stack.push(value);
-            self.current += 1;
-        } else if let Some(&last_value) = self.internal_stack.last()
-            && value > last_value
+        self.push_with(value, |_, _| {});
+    }
+
+    /// Push `value`, calling `popped` with every value dropped for it and the depth left, and
+    /// return whether it was kept.
+    fn push_with(&mut self, value: T, mut popped: impl FnMut(T, usize)) -> bool {
+        // Smaller values are dropped as long as the rest of the stream can still fill the stack.
+        while let Some(&last_value) = self.internal_stack.last()
+            && last_value < value
+            && self.items_left() > self.space_left()
         {
             self.internal_stack.pop();
+            popped(last_value, self.internal_stack.len());
+        }
+        let pushed = self.space_left() > 0;
+        if pushed {
             self.internal_stack.push(value);
-            self.current += 1;
-        } else if self.internal_stack.len() >= self.target_capacity {
-            self.current += 1;
-        } else if let Some(&last_value) = self.internal_stack.last()
-            && value <= last_value
-        {
-            self.internal_stack.push(value);
-            self.current += 1;
         }
+        self.current += 1;
+        pushed
     }
+
     fn space_left(&self) -> usize {
         self.target_capacity - self.internal_stack.len()
     }
 
     fn items_left(&self) -> usize {
-        self.input_size - self.current
+        self.input_size.saturating_sub(self.current)
     }
 
     pub fn values(&self) -> &[T] {
@@ -51,6 +57,27 @@ impl<T: Copy + PartialOrd> MonotonicStack<T> {
     }
 }
 
+impl<T: Copy + PartialOrd> MonotonicStack<T> {
+    /// [`push`](Self::push), reporting the values popped and pushed to `tracer` as the numbers
+    /// `number` makes of them.
+    pub fn push_traced(&mut self, value: T, tracer: &mut impl Tracer, number: impl Fn(T) -> u64) {
+        let pushed = self.push_with(value, |popped, depth| {
+            tracer.record(|| {
+                Event::new("pop")
+                    .subject(number(popped))
+                    .field("depth", depth)
+            })
+        });
+        if pushed {
+            tracer.record(|| {
+                Event::new("push")
+                    .subject(number(value))
+                    .field("depth", self.internal_stack.len())
+            });
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::day3::monotonic_stack::MonotonicStack;
@@ -81,6 +108,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pushes_beyond_input_size_fill_space_left() {
+        let mut monotonic_stack = MonotonicStack::new(3, 1);
+        for value in [1, 5, 3, 7] {
+            monotonic_stack.push(value);
+        }
+        assert_eq!(vec![1, 5, 3], monotonic_stack.values());
+    }
+
     #[test]
     fn test_monotonic_stack_insert3() {
         let input: Vec<u32> = vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9];
@@ -0,0 +1,74 @@
+//! Naive reference implementations to test the solutions against.
+
+/// The highest joltage of switching on `count` of the batteries, trying every combination.
+///
+/// Only feasible for banks of about twenty batteries.
+pub fn highest_joltage_exhaustive(batteries: &[char], count: usize) -> Option<u64> {
+    assert!(
+        batteries.len() < 32,
+        "too many batteries to try every combination"
+    );
+    (0u32..1 << batteries.len())
+        .filter(|switched_on| switched_on.count_ones() as usize == count)
+        .filter_map(|switched_on| {
+            let digits: String = (0..batteries.len())
+                .filter(|i| switched_on & (1 << i) != 0)
+                .map(|i| batteries[i])
+                .collect();
+            digits.parse().ok()
+        })
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day3::Bank;
+    use crate::day3::reference::highest_joltage_exhaustive;
+    use crate::differential::{Config, compare, shrink_number, shrink_vec};
+    use std::error::Error;
+
+    fn generate_bank(rng: &mut crate::generate::Rng, min_len: u64) -> Vec<char> {
+        let len = rng.range(min_len..=16);
+        (0..len)
+            .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+            .collect()
+    }
+
+    fn shrink_bank(batteries: &[char], min_len: usize) -> Vec<Vec<char>> {
+        let shrink_digit = |battery: &char| {
+            let digit = battery.to_digit(10).unwrap_or(1) as u64;
+            shrink_number(digit, 1)
+                .into_iter()
+                .map(|digit| char::from(b'0' + digit as u8))
+                .collect()
+        };
+        shrink_vec(batteries, shrink_digit)
+            .into_iter()
+            .filter(|smaller| smaller.len() >= min_len)
+            .collect()
+    }
+
+    #[test]
+    fn highest_joltage_matches_exhaustive_search() -> Result<(), Box<dyn Error>> {
+        compare(
+            &Config::default(),
+            |rng| generate_bank(rng, 2),
+            |batteries| Some(Bank::new(batteries).ok()?.find_highest_joltage().ok()? as u64),
+            |batteries| highest_joltage_exhaustive(batteries, 2),
+            |batteries| shrink_bank(batteries, 2),
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn monotonic_stack_matches_exhaustive_search() -> Result<(), Box<dyn Error>> {
+        compare(
+            &Config::default(),
+            |rng| generate_bank(rng, 0),
+            |batteries| Bank::new(batteries).ok()?.find_highest_joltage_v2().ok(),
+            |batteries| highest_joltage_exhaustive(batteries, 12),
+            |batteries| shrink_bank(batteries, 0),
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::error::ParseErrorKind;
+use std::fmt::{Display, Formatter};
+
+pub type ParseError = crate::error::ParseError<ErrorKind>;
+pub type ReadError = crate::error::ReadError<ErrorKind>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ErrorKind {
+    EmptyInput,
+    UnknownCell(char),
+    UnevenRow { expected: usize, found: usize },
+}
+
+impl ParseErrorKind for ErrorKind {
+    const DAY: u8 = 4;
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::EmptyInput => write!(f, "expected a grid of paper rolls"),
+            ErrorKind::UnknownCell(cell) => write!(f, "unknown cell '{cell}', expected '@' or '.'"),
+            ErrorKind::UnevenRow { expected, found } => {
+                write!(f, "row has {found} cells, expected {expected}")
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::day4::Day4;
+use crate::generate::{Generate, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub width: usize,
+    pub height: usize,
+    /// Probability of a position holding a roll of paper.
+    pub density: f64,
+}
+
+impl Generate for Day4 {
+    type Params = Params;
+
+    /// A grid of `size` by `size` positions, but at least one, as a grid cannot be empty.
+    fn params(size: usize) -> Params {
+        Params {
+            width: size.max(1),
+            height: size.max(1),
+            density: 0.6,
+        }
+    }
+
+    fn generate(params: &Params, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..params.height {
+            let row = (0..params.width).map(|_| match rng.chance(params.density) {
+                true => '@',
+                false => '.',
+            });
+            input.extend(row);
+            input.push('\n');
+        }
+        input
+    }
+}
@@ -1,3 +1,17 @@
+/// Serialised as its rows, which are checked like [`Matrix::new`] does when deserialised.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "Vec<Vec<T>>",
+        into = "Vec<Vec<T>>",
+        bound(
+            serialize = "T: Copy + serde::Serialize",
+            deserialize = "T: Copy + serde::Deserialize<'de>"
+        )
+    )
+)]
 pub struct Matrix<T> {
     cols: usize,
     rows: usize,
@@ -5,12 +19,27 @@ pub struct Matrix<T> {
 }
 
 impl<T: Copy> Matrix<T> {
-    pub fn new(data: Vec<Vec<T>>) -> Self {
+    /// A matrix of the given rows, `None` unless there is at least one row and every row has the
+    /// same, non-zero, number of elements.
+    pub fn new(data: Vec<Vec<T>>) -> Option<Self> {
         let rows = data.len();
-        let cols = data[0].len();
-        Self { cols, rows, data }
+        let cols = data.first()?.len();
+        if cols == 0 || data.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        Some(Self { cols, rows, data })
+    }
+
+    pub fn rows(&self) -> &[Vec<T>] {
+        &self.data
+    }
+
+    /// Replace the element in column `x` of row `y`.
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        self.data[y][x] = value;
     }
 
+    /// Every element in row order together with its up to eight neighbours.
     pub fn adjacent_windows(&self) -> impl Iterator<Item = View<T>> {
         AdjacentIterator {
             cols: self.cols,
@@ -23,6 +52,20 @@ impl<T: Copy> Matrix<T> {
     }
 }
 
+impl<T: Copy> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = &'static str;
+
+    fn try_from(data: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Matrix::new(data).ok_or("a matrix needs rows of the same, non-zero, length")
+    }
+}
+
+impl<T> From<Matrix<T>> for Vec<Vec<T>> {
+    fn from(matrix: Matrix<T>) -> Self {
+        matrix.data
+    }
+}
+
 struct AdjacentIterator<T> {
     cols: usize,
     rows: usize,
@@ -64,15 +107,15 @@ impl<T: Copy> AdjacentIterator<T> {
         None
     }
 
-    fn bottom_right(&self) -> Option<&T> {
+    fn bottom_right(&self) -> Option<T> {
         if self.y < self.rows - 1 && self.x < self.cols - 1 {
-            return Some(&self.data[self.y + 1][self.x + 1]);
+            return Some(self.data[self.y + 1][self.x + 1]);
         }
         None
     }
-    fn bottom(&self) -> Option<&T> {
+    fn bottom(&self) -> Option<T> {
         if self.y < self.rows - 1 {
-            return Some(&self.data[self.y + 1][self.x]);
+            return Some(self.data[self.y + 1][self.x]);
         }
         None
     }
@@ -97,7 +140,7 @@ impl<T: Copy> AdjacentIterator<T> {
     }
 }
 
-impl<T> Iterator for AdjacentIterator<T> {
+impl<T: Copy> Iterator for AdjacentIterator<T> {
     type Item = View<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -133,25 +176,19 @@ impl<T> Iterator for AdjacentIterator<T> {
         }
         let element = self.data[self.y][self.x];
         self.increment();
-        Some(View {
-            element: &element,
-            adjacent,
-        })
+        Some(View { element, adjacent })
     }
 }
 
 #[derive(PartialEq, Debug)]
-pub struct View<'a, T> {
-    element: &'a T,
+pub struct View<T> {
+    element: T,
     adjacent: Vec<T>,
 }
 
-impl<T> View<'_, T> {
-    pub fn new(element: &T, adjacent: Vec<T>) -> Self {
-        Self {
-            element: e,
-            adjacent,
-        }
+impl<T> View<T> {
+    pub fn new(element: T, adjacent: Vec<T>) -> Self {
+        Self { element, adjacent }
     }
 }
 
@@ -172,7 +209,7 @@ mod tests {
     #[test]
     fn test_iterator() {
         let data = vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]];
-        let matrix = Matrix::new(data);
+        let matrix = Matrix::new(data).unwrap();
         let mut iter = matrix.adjacent_windows();
         assert_eq!(View::new(1, vec![2, 5, 6]), iter.next().unwrap());
         assert_eq!(View::new(2, vec![1, 3, 5, 6, 7]), iter.next().unwrap());
@@ -188,9 +225,27 @@ mod tests {
     #[test]
     fn test_last_iterator() {
         let data = vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]];
-        let matrix = Matrix::new(data);
+        let matrix = Matrix::new(data).unwrap();
         let iter = matrix.adjacent_windows();
         let view = View::new(12, vec![7, 8, 11]);
         assert_eq!(view, iter.last().unwrap());
     }
+
+    #[test]
+    fn empty_or_uneven_rows_are_not_a_matrix() {
+        assert_eq!(None, Matrix::<u8>::new(vec![]));
+        assert_eq!(None, Matrix::<u8>::new(vec![vec![]]));
+        assert_eq!(None, Matrix::new(vec![vec![1, 2], vec![3]]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let matrix = Matrix::new(vec![vec!['@', '.'], vec!['.', '@']]).unwrap();
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(r#"[["@","."],[".","@"]]"#, json);
+        assert_eq!(matrix, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<Matrix<u8>>("[]").is_err());
+        assert!(serde_json::from_str::<Matrix<u8>>("[[1],[2,3]]").is_err());
+    }
 }
@@ -1,4 +1,101 @@
+//! Day 4: a grid of paper rolls (`@`) and the rolls a forklift can reach.
+//!
+//! ```
+//! use advent_of_code_2025::day4::{Matrix, PaperStorage, View};
+//!
+//! let paper_storage: PaperStorage = "@@@\n@@@\n.@.".parse()?;
+//! assert_eq!(3, paper_storage.n_forklift_accessible_paper_rolls());
+//!
+//! let matrix = Matrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+//! let first = matrix.adjacent_windows().next();
+//! assert_eq!(Some(View::new(1, vec![2, 3, 4])), first);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::render::Render;
+use crate::solution::{Snapshot, Solution};
+use crate::trace::Tracer;
+use std::error::Error;
+use std::io::BufRead;
+
+mod error;
+mod generate;
 mod matrix;
 mod paper_storage;
+
+pub use error::{ErrorKind, ParseError, ReadError};
+pub use generate::Params;
+pub use matrix::{Matrix, View};
+pub use paper_storage::{PaperStorage, read_rows};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = PaperStorage;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type ErrorKind = ErrorKind;
+
+    const EXAMPLE: &'static str = include_str!("test.txt");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn read(reader: impl BufRead) -> Result<Self::Input, ReadError> {
+        Ok(PaperStorage::from_rows(
+            read_rows(reader).collect::<Result<_, _>>()?,
+        )?)
+    }
+
+    fn part_one(paper_storage: &Self::Input) -> Result<Self::PartOne, Box<dyn Error>> {
+        Ok(paper_storage.n_forklift_accessible_paper_rolls())
+    }
+
+    fn part_two(paper_storage: &Self::Input) -> Result<Self::PartTwo, Box<dyn Error>> {
+        Ok(paper_storage.n_removable_paper_rolls())
+    }
+
+    fn render(paper_storage: Self::Input, _: Option<usize>) -> Option<Box<dyn Render>> {
+        Some(Box::new(paper_storage))
+    }
+
+    /// The paper storage after every round of removing the rolls a forklift can reach, until
+    /// none can be removed.
+    fn snapshots(paper_storage: Self::Input) -> Option<Vec<Snapshot>> {
+        let counters = |removed, total| vec![("removed this round", removed), ("removed", total)];
+        let (mut next, mut removed) = paper_storage.remove_accessible_paper_rolls();
+        let mut snapshots = vec![Snapshot {
+            event: String::from("start"),
+            state: Box::new(paper_storage),
+            counters: counters(0, 0),
+        }];
+        let mut total = 0;
+        while removed > 0 {
+            total += removed as u64;
+            let (after_next, removed_next) = next.remove_accessible_paper_rolls();
+            snapshots.push(Snapshot {
+                event: format!("round {}", snapshots.len()),
+                state: Box::new(next),
+                counters: counters(removed as u64, total),
+            });
+            (next, removed) = (after_next, removed_next);
+        }
+        Some(snapshots)
+    }
+
+    fn trace(
+        paper_storage: Self::Input,
+        mut tracer: &mut dyn Tracer,
+    ) -> Option<Result<(), Box<dyn Error>>> {
+        let (mut storage, mut removed) =
+            paper_storage.remove_accessible_paper_rolls_traced(&mut tracer);
+        while removed > 0 {
+            (storage, removed) = storage.remove_accessible_paper_rolls_traced(&mut tracer);
+        }
+        Some(Ok(()))
+    }
+}
+
 #[cfg(test)]
 mod tests;
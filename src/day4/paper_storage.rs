@@ -1,25 +1,187 @@
+use crate::day4::error::{ErrorKind, ParseError, ReadError};
 use crate::day4::matrix::Matrix;
-use std::error::Error;
+use crate::render::{Canvas, Render, Style};
+use crate::trace::{Event, NoTrace, Tracer};
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
 use std::str::FromStr;
 
+#[derive(Debug, PartialEq, Clone)]
 pub struct PaperStorage {
     matrix: Matrix<char>,
 }
 
 impl PaperStorage {
+    /// A grid of rows checked by [`read_rows`].
+    pub fn from_rows(rows: Vec<Vec<char>>) -> Result<Self, ParseError> {
+        // Rows are checked as they are parsed, so only an empty grid is not a matrix.
+        let matrix = Matrix::new(rows).ok_or(ParseError::new(ErrorKind::EmptyInput, ""))?;
+        Ok(PaperStorage { matrix })
+    }
+
+    /// Rolls with fewer than four rolls in the eight adjacent positions.
     pub fn n_forklift_accessible_paper_rolls(&self) -> usize {
-        self.matrix
-            .adjacent_windows()
-            .filter(|view| view.is_paper() && view.n_adjacent_paper_rolls() < 4)
-            .count()
+        self.accessible_paper_rolls(&mut NoTrace).len()
+    }
+
+    /// The storage after removing every roll a forklift can reach, and the number removed.
+    pub fn remove_accessible_paper_rolls(&self) -> (PaperStorage, usize) {
+        self.remove_accessible_paper_rolls_traced(&mut NoTrace)
+    }
+
+    /// [`remove_accessible_paper_rolls`](Self::remove_accessible_paper_rolls), reporting every
+    /// position checked to `tracer`.
+    pub fn remove_accessible_paper_rolls_traced(
+        &self,
+        tracer: &mut impl Tracer,
+    ) -> (PaperStorage, usize) {
+        let mut remaining = self.clone();
+        let accessible = self.accessible_paper_rolls(tracer);
+        for &(x, y) in &accessible {
+            remaining.matrix.set(x, y, '.');
+        }
+        (remaining, accessible.len())
+    }
+
+    /// Rolls removed by removing the rolls a forklift can reach until it can reach none.
+    pub fn n_removable_paper_rolls(&self) -> usize {
+        let (mut storage, mut removed) = self.remove_accessible_paper_rolls();
+        let mut total = 0;
+        while removed > 0 {
+            total += removed;
+            (storage, removed) = storage.remove_accessible_paper_rolls();
+        }
+        total
+    }
+
+    /// Columns and rows of the rolls a forklift can reach.
+    fn accessible_paper_rolls(&self, tracer: &mut impl Tracer) -> Vec<(usize, usize)> {
+        let (width, _) = self.size();
+        let mut accessible = Vec::new();
+        for (i, view) in self.matrix.adjacent_windows().enumerate() {
+            let (x, y) = (i % width, i / width);
+            let adjacent_paper_rolls = view.n_adjacent_paper_rolls();
+            let is_accessible = view.is_paper() && adjacent_paper_rolls < 4;
+            tracer.record(|| {
+                Event::new("window")
+                    .subject(format!("{x},{y}"))
+                    .field("paper", view.is_paper())
+                    .field("adjacent", adjacent_paper_rolls)
+                    .field("accessible", is_accessible)
+            });
+            if is_accessible {
+                accessible.push((x, y));
+            }
+        }
+        accessible
     }
 }
 
 impl FromStr for PaperStorage {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let vec_of_vec: Vec<Vec<char>> = s.lines().map(|l| l.chars().collect()).collect();
-        let matrix = Matrix::new(vec_of_vec);
-        Ok(PaperStorage { matrix })
+        let mut rows: Vec<Vec<char>> = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let width = rows.first().map(Vec::len);
+            let row = parse_row(line, width).map_err(|error| error.at_line(i + 1))?;
+            rows.push(row);
+        }
+        PaperStorage::from_rows(rows)
+    }
+}
+
+/// The rows of a grid, read and parsed one line at a time.
+pub fn read_rows(reader: impl BufRead) -> impl Iterator<Item = Result<Vec<char>, ReadError>> {
+    let mut width = None;
+    reader.lines().enumerate().map(move |(i, line)| {
+        let row = parse_row(&line?, width).map_err(|error| error.at_line(i + 1))?;
+        width.get_or_insert(row.len());
+        Ok(row)
+    })
+}
+
+/// A row of the grid, which must be `width` cells wide if that is known.
+fn parse_row(line: &str, width: Option<usize>) -> Result<Vec<char>, ParseError> {
+    let row: Vec<char> = line.chars().collect();
+    if let Some((j, &cell)) = row
+        .iter()
+        .enumerate()
+        .find(|(_, c)| !matches!(c, '@' | '.'))
+    {
+        return Err(ParseError::new(ErrorKind::UnknownCell(cell), cell).shift_columns(j));
+    }
+    if let Some(expected) = width
+        && expected != row.len()
+    {
+        let kind = ErrorKind::UnevenRow {
+            expected,
+            found: row.len(),
+        };
+        return Err(ParseError::new(kind, line));
+    }
+    Ok(row)
+}
+
+impl Display for PaperStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.matrix.rows() {
+            let row: String = row.iter().collect();
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The grid with the rolls a forklift can reach marked `x`, like the puzzle description.
+impl Render for PaperStorage {
+    fn size(&self) -> (usize, usize) {
+        let rows = self.matrix.rows();
+        (rows[0].len(), rows.len())
+    }
+
+    fn render(&self, canvas: &mut dyn Canvas) {
+        let (width, _) = self.size();
+        for (i, view) in self.matrix.adjacent_windows().enumerate() {
+            let (glyph, style) = match view.is_paper() {
+                true if view.n_adjacent_paper_rolls() < 4 => ('x', Style::Highlight),
+                true => ('@', Style::Normal),
+                false => ('.', Style::Dim),
+            };
+            canvas.cell(i % width, i / width, glyph, style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day4::matrix::Matrix;
+    use crate::day4::paper_storage::PaperStorage;
+    use crate::differential::{self, Config, shrink_vec};
+    use std::error::Error;
+
+    #[test]
+    fn displays_as_parsed() -> Result<(), Box<dyn Error>> {
+        differential::round_trip(
+            &Config::default(),
+            |rng| {
+                let (width, height) = (rng.range(1..=12), rng.range(1..=12));
+                let rows = (0..height)
+                    .map(|_| (0..width).map(|_| *rng.pick(&['@', '.'])).collect())
+                    .collect();
+                PaperStorage {
+                    matrix: Matrix::new(rows).unwrap(),
+                }
+            },
+            |storage| {
+                shrink_vec(storage.matrix.rows(), |_| Vec::new())
+                    .into_iter()
+                    .filter(|rows| !rows.is_empty())
+                    .map(|rows| PaperStorage {
+                        matrix: Matrix::new(rows).unwrap(),
+                    })
+                    .collect()
+            },
+        )?;
+        Ok(())
     }
 }
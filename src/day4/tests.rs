@@ -1,21 +1,51 @@
 use crate::day4::paper_storage::PaperStorage;
-use std::error::Error;
-use std::str::FromStr;
+use crate::day4::{Day4, ErrorKind, ReadError};
+use crate::generate::assert_read_matches_parse;
+use crate::solution::Solution;
 
 #[test]
-fn day4_part1_example1() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("test.txt");
-    let paper_storage: PaperStorage = input.parse()?;
-    let result = paper_storage.n_forklift_accessible_paper_rolls();
-    assert_eq!(13, result);
-    Ok(())
+fn day4_parse_error_reports_position() {
+    let error = "..@\n.#@".parse::<PaperStorage>().unwrap_err();
+    assert_eq!(&ErrorKind::UnknownCell('#'), error.kind());
+    assert_eq!(2, error.line());
+    assert_eq!(2, error.column());
 }
 
 #[test]
-fn day4_part1_solution() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("input.txt");
-    let paper_storage = PaperStorage::from_str(input)?;
-    let result = paper_storage.n_forklift_accessible_paper_rolls();
-    assert_eq!(1523, result);
-    Ok(())
+fn day4_parse_error_reports_uneven_row() {
+    let error = "..@\n.@".parse::<PaperStorage>().unwrap_err();
+    let expected = ErrorKind::UnevenRow {
+        expected: 3,
+        found: 2,
+    };
+    assert_eq!(&expected, error.kind());
+    assert_eq!(2, error.line());
+}
+
+#[test]
+fn day4_empty_grid_is_an_error() {
+    for input in ["", "\n\n"] {
+        let error = input.parse::<PaperStorage>().unwrap_err();
+        assert_eq!(&ErrorKind::EmptyInput, error.kind());
+    }
+}
+
+#[test]
+fn day4_read_matches_parse() {
+    assert_read_matches_parse::<Day4>(&[]);
+}
+
+#[test]
+fn day4_read_reports_uneven_row() {
+    let error = Day4::read("..@\n.@\n".as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Parse(error) if error.line() == 2));
+}
+
+#[test]
+fn day4_removes_rolls_in_waves() {
+    let paper_storage = Day4::parse(Day4::EXAMPLE).unwrap();
+    let (remaining, removed) = paper_storage.remove_accessible_paper_rolls();
+    assert_eq!(13, removed);
+    assert_eq!(12, remaining.n_forklift_accessible_paper_rolls());
+    assert_eq!(43, paper_storage.n_removable_paper_rolls());
 }
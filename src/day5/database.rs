@@ -1,24 +1,37 @@
+use crate::day5::error::{ErrorKind, ParseError, ReadError};
 use crate::day5::ingredient_id::IngredientId;
 use crate::day5::ingredient_id_range::IngredientIdRange;
+use crate::error::leading_whitespace;
+use crate::trace::{Event, NoTrace, Tracer};
 use std::collections::HashSet;
-use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
 use std::str::FromStr;
 
+#[derive(Debug, Clone)]
 pub struct Database {
+    /// The fresh ranges as given, before merging.
+    ranges: Vec<IngredientIdRange>,
     fresh_ingredients: HashSet<IngredientIdRange>,
     ingredients: Vec<IngredientId>,
 }
 
 impl Database {
     pub fn new(fresh_ingredients: Vec<IngredientIdRange>, ingredients: Vec<IngredientId>) -> Self {
-        let set = HashSet::from_iter(fresh_ingredients);
+        let set = HashSet::from_iter(fresh_ingredients.iter().cloned());
         let reduced_fresh_ingredients = Self::reduce_fresh_ingredient_ranges(&set);
         Self {
+            ranges: fresh_ingredients,
             fresh_ingredients: reduced_fresh_ingredients,
             ingredients,
         }
     }
 
+    /// The fresh ranges in the order they were given, before overlapping ranges were merged.
+    pub fn ranges(&self) -> &[IngredientIdRange] {
+        &self.ranges
+    }
+
     pub fn find_fresh_ingredients(&self) -> Vec<IngredientId> {
         self.ingredients
             .iter()
@@ -42,10 +55,24 @@ impl Database {
     /// # Examples
     ///
     /// ```
+    /// use advent_of_code_2025::day5::{Database, IngredientIdRange};
+    /// use std::collections::HashSet;
     ///
+    /// let ranges = HashSet::from([IngredientIdRange::new(3, 5), IngredientIdRange::new(5, 8)]);
+    /// let reduced = Database::reduce_fresh_ingredient_ranges(&ranges);
+    /// assert_eq!(HashSet::from([IngredientIdRange::new(3, 8)]), reduced);
     /// ```
     pub fn reduce_fresh_ingredient_ranges(
         ingredient_ids: &HashSet<IngredientIdRange>,
+    ) -> HashSet<IngredientIdRange> {
+        Self::reduce_fresh_ingredient_ranges_traced(ingredient_ids, &mut NoTrace)
+    }
+
+    /// [`reduce_fresh_ingredient_ranges`](Self::reduce_fresh_ingredient_ranges), reporting every
+    /// merge to `tracer`.
+    pub fn reduce_fresh_ingredient_ranges_traced(
+        ingredient_ids: &HashSet<IngredientIdRange>,
+        tracer: &mut impl Tracer,
     ) -> HashSet<IngredientIdRange> {
         let mut new: HashSet<IngredientIdRange> = HashSet::new();
         let mut found_any_overlap = false;
@@ -56,7 +83,14 @@ impl Database {
                     continue;
                 }
                 if range_a.overlaps(range_b) {
-                    new.insert(range_a.merge(range_b));
+                    let merged = range_a.merge(range_b);
+                    tracer.record(|| {
+                        Event::new("merge")
+                            .subject(range_a.to_string())
+                            .subject(range_b.to_string())
+                            .field("into", merged.to_string())
+                    });
+                    new.insert(merged);
                     found_overlap = true;
                 }
             }
@@ -70,37 +104,103 @@ impl Database {
             return new;
         }
 
-        Self::reduce_fresh_ingredient_ranges(&new)
+        Self::reduce_fresh_ingredient_ranges_traced(&new, tracer)
     }
 
-    pub fn n_fresh_ingredients_in_index(&self) -> u64 {
+    /// The number of ids in the fresh ranges, `None` if that does not fit in a `u64`.
+    pub fn n_fresh_ingredients_in_index(&self) -> Option<u64> {
         self.fresh_ingredients
             .iter()
-            .map(IngredientIdRange::size)
-            .sum()
+            .try_fold(0u64, |total, range| total.checked_add(range.size()?))
     }
 }
 
 impl FromStr for Database {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut ingredients: Vec<IngredientId> = Vec::new();
-        let mut fresh_ingredients: Vec<IngredientIdRange> = Vec::new();
+        s.lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                parse_record(line)
+                    .map_err(|error| error.at_line(i + 1))
+                    .transpose()
+            })
+            .collect()
+    }
+}
 
-        for line in s.lines() {
-            if line.trim().is_empty() {
-                continue;
-            }
-            if line.contains('-') {
-                let range: IngredientIdRange = line.parse()?;
-                fresh_ingredients.push(range);
-            } else {
-                let id: IngredientId = line.parse()?;
-                ingredients.push(id);
+impl FromIterator<Record> for Database {
+    fn from_iter<I: IntoIterator<Item = Record>>(records: I) -> Self {
+        let mut fresh_ingredients: Vec<IngredientIdRange> = Vec::new();
+        let mut ingredients: Vec<IngredientId> = Vec::new();
+        for record in records {
+            match record {
+                Record::Fresh(range) => fresh_ingredients.push(range),
+                Record::Available(id) => ingredients.push(id),
             }
         }
+        Database::new(fresh_ingredients, ingredients)
+    }
+}
+
+/// A line of the input: a range of fresh ingredient ids or an available ingredient.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Record {
+    Fresh(IngredientIdRange),
+    Available(IngredientId),
+}
 
-        Ok(Database::new(fresh_ingredients, ingredients))
+/// The record on a line, `None` if the line is blank.
+fn parse_record(line: &str) -> Result<Option<Record>, ParseError> {
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+    if line.contains('-') {
+        return Ok(Some(Record::Fresh(line.parse()?)));
+    }
+    let id = line.parse().map_err(|error| {
+        ParseError::new(ErrorKind::InvalidIngredientId(error), line.trim())
+            .shift_columns(leading_whitespace(line))
+    })?;
+    Ok(Some(Record::Available(id)))
+}
+
+/// The records of an input, read and parsed one line at a time.
+pub fn read_records(reader: impl BufRead) -> impl Iterator<Item = Result<Record, ReadError>> {
+    reader.lines().enumerate().filter_map(|(i, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => return Some(Err(error.into())),
+        };
+        parse_record(&line)
+            .map_err(|error| error.at_line(i + 1).into())
+            .transpose()
+    })
+}
+
+/// Databases are equal when their merged fresh ranges and available ingredients are, however
+/// the fresh ranges were given.
+impl PartialEq for Database {
+    fn eq(&self, other: &Self) -> bool {
+        self.fresh_ingredients == other.fresh_ingredients && self.ingredients == other.ingredients
+    }
+}
+
+/// The merged fresh ranges ordered by start, a blank line and the available ingredients, like
+/// the puzzle input.
+impl Display for Database {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut fresh_ingredients: Vec<&IngredientIdRange> =
+            self.fresh_ingredients.iter().collect();
+        fresh_ingredients.sort_by_key(|range| range.start());
+        for range in fresh_ingredients {
+            writeln!(f, "{range}")?;
+        }
+        writeln!(f)?;
+        for id in &self.ingredients {
+            writeln!(f, "{id}")?;
+        }
+        Ok(())
     }
 }
 
@@ -109,6 +209,7 @@ mod tests {
     use crate::day5::database::Database;
     use crate::day5::ingredient_id::IngredientId;
     use crate::day5::ingredient_id_range::IngredientIdRange;
+    use crate::differential::{self, Config, shrink_vec};
     use std::collections::HashSet;
     use std::error::Error;
 
@@ -128,6 +229,8 @@ mod tests {
             IngredientIdRange::new(3, 4),
         ]);
         assert_eq!(expected_fresh_ingredients, database.fresh_ingredients);
+        let ranges = [IngredientIdRange::new(1, 2), IngredientIdRange::new(3, 4)];
+        assert_eq!(ranges, database.ranges());
         let expected_ingredients: Vec<IngredientId> = vec![1.into(), 2.into(), 3.into()];
         assert_eq!(expected_ingredients, database.ingredients);
         Ok(())
@@ -148,4 +251,41 @@ mod tests {
         ]);
         assert_eq!(expected, result)
     }
+
+    #[test]
+    fn displays_as_parsed() -> Result<(), Box<dyn Error>> {
+        differential::round_trip(
+            &Config::default(),
+            |rng| {
+                let ranges = (0..rng.range(0..=8))
+                    .map(|_| {
+                        let start = rng.range(0..=1000);
+                        IngredientIdRange::new(start, start + rng.range(0..=100))
+                    })
+                    .collect();
+                let ids = (0..rng.range(0..=8))
+                    .map(|_| rng.range(0..=1100).into())
+                    .collect();
+                Database::new(ranges, ids)
+            },
+            |database| {
+                let ranges: Vec<IngredientIdRange> =
+                    database.fresh_ingredients.iter().cloned().collect();
+                let fewer_ranges = shrink_vec(&ranges, |_| Vec::new())
+                    .into_iter()
+                    .map(|ranges| Database::new(ranges, database.ingredients.clone()));
+                let fewer_ids = shrink_vec(&database.ingredients, |_| Vec::new())
+                    .into_iter()
+                    .map(|ids| Database::new(ranges.clone(), ids));
+                fewer_ranges.chain(fewer_ids).collect()
+            },
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn displays_sorted_ranges_before_ids() {
+        let database: Database = "10-14\n3-5\n12-18\n\n5\n1\n".parse().unwrap();
+        assert_eq!("3-5\n10-18\n\n5\n1\n", database.to_string());
+    }
 }
@@ -0,0 +1,27 @@
+use crate::error::ParseErrorKind;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+
+pub type ParseError = crate::error::ParseError<ErrorKind>;
+pub type ReadError = crate::error::ReadError<ErrorKind>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ErrorKind {
+    MissingSeparator,
+    InvalidIngredientId(ParseIntError),
+    ReversedRange,
+}
+
+impl ParseErrorKind for ErrorKind {
+    const DAY: u8 = 5;
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MissingSeparator => write!(f, "expected a range like '3-5'"),
+            ErrorKind::InvalidIngredientId(error) => write!(f, "invalid ingredient id, {error}"),
+            ErrorKind::ReversedRange => write!(f, "range ends before it starts"),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::day5::Day5;
+use crate::generate::{Generate, Rng};
+use std::fmt::Write;
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub ranges: usize,
+    pub ids: usize,
+    /// Largest id, zero counts as one, as ids start at one.
+    pub max_id: u64,
+    /// Most ids in a fresh range besides the first.
+    pub max_span: u64,
+}
+
+impl Generate for Day5 {
+    type Params = Params;
+
+    fn params(size: usize) -> Params {
+        Params {
+            ranges: size,
+            ids: size,
+            max_id: 1_000_000_000_000_000,
+            max_span: 10_000_000_000_000,
+        }
+    }
+
+    fn generate(params: &Params, rng: &mut Rng) -> String {
+        let max_id = params.max_id.max(1);
+        let ranges: Vec<(u64, u64)> = (0..params.ranges)
+            .map(|_| {
+                let from = rng.range(1..=max_id);
+                let to = from
+                    .saturating_add(rng.range(0..=params.max_span))
+                    .min(max_id);
+                (from, to)
+            })
+            .collect();
+        let mut input = String::new();
+        for (from, to) in &ranges {
+            let _ = writeln!(input, "{from}-{to}");
+        }
+        input.push('\n');
+        for _ in 0..params.ids {
+            // Pick half of the ids from the ranges, random ids are hardly ever fresh.
+            let id = match ranges.is_empty() || rng.chance(0.5) {
+                true => rng.range(1..=max_id),
+                false => {
+                    let (from, to) = *rng.pick(&ranges);
+                    rng.range(from..=to)
+                }
+            };
+            let _ = writeln!(input, "{id}");
+        }
+        input
+    }
+}
@@ -1,8 +1,10 @@
+use std::fmt::{Display, Formatter};
 use std::num::ParseIntError;
 use std::ops::Sub;
 use std::str::FromStr;
 
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IngredientId(u64);
 
 impl FromStr for IngredientId {
@@ -12,6 +14,12 @@ impl FromStr for IngredientId {
     }
 }
 
+impl Display for IngredientId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl Sub for IngredientId {
     type Output = u64;
 
@@ -25,3 +33,31 @@ impl From<u64> for IngredientId {
         Self(id)
     }
 }
+
+impl From<IngredientId> for u64 {
+    fn from(id: IngredientId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day5::ingredient_id::IngredientId;
+    use crate::differential::{self, Config, shrink_number};
+    use std::error::Error;
+
+    #[test]
+    fn displays_as_parsed() -> Result<(), Box<dyn Error>> {
+        differential::round_trip(
+            &Config::default(),
+            |rng| IngredientId(rng.range(0..=u64::MAX)),
+            |id| {
+                shrink_number(id.0, 0)
+                    .into_iter()
+                    .map(IngredientId)
+                    .collect()
+            },
+        )?;
+        Ok(())
+    }
+}
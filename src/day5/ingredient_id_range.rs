@@ -1,19 +1,28 @@
+use crate::day5::error::{ErrorKind, ParseError};
 use crate::day5::ingredient_id::IngredientId;
+use crate::error::leading_whitespace;
 use std::cmp::{max, min};
-use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
+/// Serialised like a line of the input, so that reversed ranges are rejected when deserialised.
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
 pub struct IngredientIdRange {
     start: IngredientId,
     end: IngredientId,
 }
 
 impl IngredientIdRange {
+    /// The ids from `start` to `end` inclusive, swapped if `end` is below `start`.
     pub fn new(start: u64, end: u64) -> Self {
         IngredientIdRange {
-            start: start.into(),
-            end: end.into(),
+            start: min(start, end).into(),
+            end: max(start, end).into(),
         }
     }
     pub fn is_ingredient_fresh(&self, ingredient_id: &IngredientId) -> bool {
@@ -24,11 +33,6 @@ impl IngredientIdRange {
         self.start <= other.end && other.start <= self.end
     }
 
-    pub fn overlaps_pair(pair: &[IngredientIdRange]) -> bool {
-        assert_eq!(2, pair.len());
-        pair[0].start <= pair[1].end && pair[1].start <= pair[0].end
-    }
-
     pub fn merge(&self, other: &IngredientIdRange) -> Self {
         let start = min(self.start, other.start);
         let end = max(self.end, other.end);
@@ -56,29 +60,65 @@ impl IngredientIdRange {
         self.end
     }
 
-    pub fn size(&self) -> u64 {
-        self.end - self.start + 1
+    /// The number of ids in the range, `None` if that does not fit in a `u64`.
+    pub fn size(&self) -> Option<u64> {
+        (self.end - self.start).checked_add(1)
     }
 }
 
 impl FromStr for IngredientIdRange {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.trim().split_once('-');
-        if let Some((start, end)) = parts {
-            return Ok(Self {
-                start: start.parse()?,
-                end: end.parse()?,
-            });
+        let indent = leading_whitespace(s);
+        let trimmed = s.trim();
+        let parse = |id: &str, column: usize| {
+            id.parse().map_err(|error| {
+                ParseError::new(ErrorKind::InvalidIngredientId(error), id).shift_columns(column)
+            })
+        };
+        if let Some((start, end)) = trimmed.split_once('-') {
+            let range = Self {
+                start: parse(start, indent)?,
+                end: parse(end, indent + start.chars().count() + 1)?,
+            };
+            if range.end < range.start {
+                return Err(
+                    ParseError::new(ErrorKind::ReversedRange, trimmed).shift_columns(indent)
+                );
+            }
+            return Ok(range);
         }
-        Err("Could not parse ingredient range".into())
+        Err(ParseError::new(ErrorKind::MissingSeparator, trimmed).shift_columns(indent))
+    }
+}
+
+impl Display for IngredientIdRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+impl TryFrom<String> for IngredientIdRange {
+    type Error = ParseError;
+
+    fn try_from(range: String) -> Result<Self, Self::Error> {
+        range.parse()
+    }
+}
+
+impl From<IngredientIdRange> for String {
+    fn from(range: IngredientIdRange) -> Self {
+        range.to_string()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::day5::error::ErrorKind;
     use crate::day5::ingredient_id::IngredientId;
     use crate::day5::ingredient_id_range::IngredientIdRange;
+    use crate::differential::{self, Config, shrink_number};
+    use std::error::Error;
 
     #[test]
     fn test_ingredient_id_range() {
@@ -126,6 +166,68 @@ mod tests {
 
     #[test]
     fn test_ingredient_id_size() {
-        assert_eq!(3, IngredientIdRange::new(3, 5).size());
+        assert_eq!(Some(3), IngredientIdRange::new(3, 5).size());
+        assert_eq!(None, IngredientIdRange::new(0, u64::MAX).size());
+    }
+
+    #[test]
+    fn new_orders_reversed_ends() -> Result<(), Box<dyn Error>> {
+        let range = IngredientIdRange::new(5, 3);
+        assert_eq!(IngredientIdRange::new(3, 5), range);
+        assert_eq!(Some(3), range.size());
+        assert_eq!(range, range.to_string().parse()?);
+        Ok(())
+    }
+
+    #[test]
+    fn reversed_range_is_an_error() {
+        let error = "36-18".parse::<IngredientIdRange>().unwrap_err();
+        assert_eq!(&ErrorKind::ReversedRange, error.kind());
+        assert_eq!("36-18", error.text());
+    }
+
+    #[test]
+    fn invalid_end_points_at_end() {
+        let error = "10-1o".parse::<IngredientIdRange>().unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::InvalidIngredientId(_)));
+        assert_eq!(4, error.column());
+        assert_eq!("1o", error.text());
+    }
+
+    #[test]
+    fn displays_as_parsed() -> Result<(), Box<dyn Error>> {
+        differential::round_trip(
+            &Config::default(),
+            |rng| {
+                let start = rng.range(0..=u64::MAX);
+                IngredientIdRange::new(start, rng.range(start..=u64::MAX))
+            },
+            |range| {
+                let (start, end) = (u64::from(range.start), u64::from(range.end));
+                let end_shrunk = shrink_number(end, start)
+                    .into_iter()
+                    .map(|end| IngredientIdRange::new(start, end));
+                let start_shrunk = shrink_number(start, 0)
+                    .into_iter()
+                    .map(|lower| IngredientIdRange::new(lower, end - (start - lower)));
+                end_shrunk.chain(start_shrunk).collect()
+            },
+        )?;
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let range = IngredientIdRange::new(3, 5);
+        let json = serde_json::to_string(&range).unwrap();
+        assert_eq!(r#""3-5""#, json);
+        assert_eq!(range, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<IngredientIdRange>(r#""5-1""#).is_err());
+        let id = IngredientId::from(17);
+        assert_eq!(
+            id,
+            serde_json::from_str(&serde_json::to_string(&id).unwrap()).unwrap()
+        );
     }
 }
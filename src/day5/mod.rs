@@ -1,5 +1,72 @@
+//! Day 5: ranges of fresh ingredient ids and a list of available ingredients.
+//!
+//! ```
+//! use advent_of_code_2025::day5::{Database, IngredientId, IngredientIdRange};
+//!
+//! let database = Database::new(
+//!     vec![IngredientIdRange::new(3, 5), IngredientIdRange::new(4, 8)],
+//!     vec![IngredientId::from(1), IngredientId::from(7)],
+//! );
+//! assert_eq!(vec![IngredientId::from(7)], database.find_fresh_ingredients());
+//! assert_eq!(Some(6), database.n_fresh_ingredients_in_index());
+//! ```
+
+use crate::solution::Solution;
+use crate::trace::Tracer;
+use std::error::Error;
+use std::io::BufRead;
+
 mod database;
+mod error;
+mod generate;
 mod ingredient_id;
 mod ingredient_id_range;
+#[cfg(test)]
+mod reference;
+
+pub use database::{Database, Record, read_records};
+pub use error::{ErrorKind, ParseError, ReadError};
+pub use generate::Params;
+pub use ingredient_id::IngredientId;
+pub use ingredient_id_range::IngredientIdRange;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Database;
+    type PartOne = usize;
+    type PartTwo = u64;
+    type ErrorKind = ErrorKind;
+
+    const EXAMPLE: &'static str = include_str!("test.txt");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn read(reader: impl BufRead) -> Result<Self::Input, ReadError> {
+        read_records(reader).collect()
+    }
+
+    fn part_one(database: &Self::Input) -> Result<Self::PartOne, Box<dyn Error>> {
+        Ok(database.find_fresh_ingredients().len())
+    }
+
+    fn part_two(database: &Self::Input) -> Result<Self::PartTwo, Box<dyn Error>> {
+        database
+            .n_fresh_ingredients_in_index()
+            .ok_or("more fresh ingredient ids than fit in 64 bits".into())
+    }
+
+    fn trace(
+        database: Self::Input,
+        mut tracer: &mut dyn Tracer,
+    ) -> Option<Result<(), Box<dyn Error>>> {
+        let ranges = database.ranges().iter().cloned().collect();
+        Database::reduce_fresh_ingredient_ranges_traced(&ranges, &mut tracer);
+        Some(Ok(()))
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,93 @@
+//! Naive reference implementations to test the solutions against.
+
+/// Mark every fresh id in a bitmap, returning the number of fresh ids and which of `ids` are
+/// fresh.
+///
+/// Only feasible for small ids.
+pub fn fresh_by_bitmap(ranges: &[(u64, u64)], ids: &[u64]) -> (u64, Vec<u64>) {
+    let max = ranges
+        .iter()
+        .map(|&(_, end)| end)
+        .chain(ids.iter().copied())
+        .max();
+    let mut fresh = vec![false; max.map_or(0, |max| max as usize + 1)];
+    for &(start, end) in ranges {
+        for id in start..=end {
+            fresh[id as usize] = true;
+        }
+    }
+    let count = fresh.iter().filter(|&&fresh| fresh).count() as u64;
+    let fresh_ids = ids
+        .iter()
+        .copied()
+        .filter(|&id| fresh[id as usize])
+        .collect();
+    (count, fresh_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day5::reference::fresh_by_bitmap;
+    use crate::day5::{Database, IngredientId, IngredientIdRange};
+    use crate::differential::{Config, compare, shrink_number, shrink_vec};
+    use std::error::Error;
+
+    type Case = (Vec<(u64, u64)>, Vec<u64>);
+
+    fn shrink_range(&(start, end): &(u64, u64)) -> Vec<(u64, u64)> {
+        let shorter = shrink_number(end, start)
+            .into_iter()
+            .map(|end| (start, end));
+        let lower = shrink_number(start, 0)
+            .into_iter()
+            .map(|lower| (lower, end - (start - lower)));
+        shorter.chain(lower).collect()
+    }
+
+    fn shrink_case((ranges, ids): &Case) -> Vec<Case> {
+        let fewer_ranges = shrink_vec(ranges, shrink_range)
+            .into_iter()
+            .map(|ranges| (ranges, ids.clone()));
+        let fewer_ids = shrink_vec(ids, |&id| shrink_number(id, 0))
+            .into_iter()
+            .map(|ids| (ranges.clone(), ids));
+        fewer_ranges.chain(fewer_ids).collect()
+    }
+
+    #[test]
+    fn merged_ranges_cover_the_same_ids_as_a_bitmap() -> Result<(), Box<dyn Error>> {
+        compare(
+            &Config::default(),
+            |rng| {
+                let ranges = (0..rng.range(0..=8))
+                    .map(|_| {
+                        let start = rng.range(0..=60);
+                        (start, start + rng.range(0..=20))
+                    })
+                    .collect();
+                let ids = (0..rng.range(0..=8)).map(|_| rng.range(0..=90)).collect();
+                (ranges, ids)
+            },
+            |(ranges, ids): &Case| {
+                let ranges = ranges
+                    .iter()
+                    .map(|&(start, end)| IngredientIdRange::new(start, end))
+                    .collect();
+                let ids = ids.iter().copied().map(IngredientId::from).collect();
+                let database = Database::new(ranges, ids);
+                let fresh_ids = database
+                    .find_fresh_ingredients()
+                    .into_iter()
+                    .map(u64::from)
+                    .collect();
+                (database.n_fresh_ingredients_in_index(), fresh_ids)
+            },
+            |(ranges, ids): &Case| {
+                let (n_fresh, fresh_ids) = fresh_by_bitmap(ranges, ids);
+                (Some(n_fresh), fresh_ids)
+            },
+            shrink_case,
+        )?;
+        Ok(())
+    }
+}
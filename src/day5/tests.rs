@@ -1,38 +1,35 @@
 use crate::day5::database::Database;
-use std::error::Error;
+use crate::day5::{Day5, ErrorKind, Params, ReadError};
+use crate::generate::{Generate, Rng, assert_read_matches_parse};
+use crate::solution::Solution;
 
 #[test]
-fn day5_part1_example() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("test.txt");
-    let database: Database = input.parse()?;
-    let fresh_ingredients = database.find_fresh_ingredients();
-    assert_eq!(3, fresh_ingredients.len());
-    Ok(())
+fn day5_parse_error_reports_line() {
+    let error = "3-5\n10-14\n\n1\n5x\n".parse::<Database>().unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::InvalidIngredientId(_)));
+    assert_eq!(5, error.line());
+    assert_eq!(1, error.column());
+    assert_eq!("5x", error.text());
 }
 
 #[test]
-fn day5_part1_solution() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("input.txt");
-    let database: Database = input.parse()?;
-    let fresh_ingredients = database.find_fresh_ingredients();
-    assert_eq!(615, fresh_ingredients.len());
-    Ok(())
+fn day5_read_matches_parse() {
+    assert_read_matches_parse::<Day5>(&[]);
 }
 
 #[test]
-fn day5_part2_example() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("test.txt");
-    let database: Database = input.parse()?;
-    let fresh_ingredients = database.n_fresh_ingredients_in_index();
-    assert_eq!(14, fresh_ingredients);
-    Ok(())
+fn day5_read_reports_line() {
+    let error = Day5::read("3-5\n10-14\n\n1\n5x\n".as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Parse(error) if error.line() == 5));
 }
 
 #[test]
-fn day5_part2_solution() -> Result<(), Box<dyn Error>> {
-    let input = include_str!("input.txt");
-    let database: Database = input.parse()?;
-    let fresh_ingredients = database.n_fresh_ingredients_in_index();
-    assert_eq!(353716783056994, fresh_ingredients);
-    Ok(())
+fn day5_generates_ids_of_one_for_zero_max_id() {
+    let params = Params {
+        max_id: 0,
+        ..Day5::params(5)
+    };
+    let input = Day5::generate(&params, &mut Rng::new(0));
+    let database = Day5::parse(&input).unwrap();
+    assert_eq!(5, Day5::part_one(&database).unwrap());
 }
@@ -0,0 +1,32 @@
+use crate::error::ParseErrorKind;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+
+pub type ParseError = crate::error::ParseError<ErrorKind>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ErrorKind {
+    MissingOperations,
+    UnevenRow { expected: usize, found: usize },
+    UnknownOperation,
+    InvalidTerm(ParseIntError),
+}
+
+impl ParseErrorKind for ErrorKind {
+    const DAY: u8 = 6;
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::MissingOperations => {
+                write!(f, "expected rows of terms followed by a row of operations")
+            }
+            ErrorKind::UnevenRow { expected, found } => {
+                write!(f, "row has {found} columns, expected {expected}")
+            }
+            ErrorKind::UnknownOperation => write!(f, "unknown operation, expected '+' or '*'"),
+            ErrorKind::InvalidTerm(error) => write!(f, "invalid term, {error}"),
+        }
+    }
+}
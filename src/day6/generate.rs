@@ -0,0 +1,52 @@
+use crate::day6::Day6;
+use crate::generate::{Generate, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub problems: usize,
+    /// Terms of every problem, the rows above the operations.
+    pub terms: usize,
+    /// Most digits of a term, from 1 to 19, as larger terms do not fit in a `u64`. Values outside
+    /// that are clamped to it.
+    pub max_digits: u32,
+}
+
+impl Generate for Day6 {
+    type Params = Params;
+
+    /// `size` problems, but at least one, as the homework cannot be empty.
+    fn params(size: usize) -> Params {
+        Params {
+            problems: size.max(1),
+            terms: 4,
+            max_digits: 4,
+        }
+    }
+
+    /// Every problem is a block of columns as wide as its longest term, with its terms aligned
+    /// either left or right and its operation in the first column of the last row.
+    fn generate(params: &Params, rng: &mut Rng) -> String {
+        let max_digits = params.max_digits.clamp(1, 19);
+        let mut rows = vec![Vec::new(); params.terms + 1];
+        for _ in 0..params.problems {
+            let terms: Vec<String> = (0..params.terms)
+                .map(|_| {
+                    let digits = rng.range(1..=u64::from(max_digits)) as u32;
+                    rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1)
+                        .to_string()
+                })
+                .collect();
+            let width = terms.iter().map(String::len).max().unwrap_or(1);
+            let left = rng.chance(0.5);
+            for (row, term) in rows.iter_mut().zip(&terms) {
+                row.push(match left {
+                    true => format!("{term:<width$}"),
+                    false => format!("{term:>width$}"),
+                });
+            }
+            let operation = rng.pick(&['+', '*']);
+            rows[params.terms].push(format!("{operation:<width$}"));
+        }
+        rows.iter().map(|row| row.join(" ") + "\n").collect()
+    }
+}
@@ -1,30 +1,175 @@
-use crate::day6::problem::Problem;
+use crate::day6::error::{ErrorKind, ParseError};
+use crate::day6::problem::{Operation, Problem};
+use crate::error::leading_whitespace;
+use crate::render::{Canvas, Render, Style};
 use ndarray::Array2;
-use std::error::Error;
 use std::str::FromStr;
 
-struct Homework {
+#[derive(Debug, PartialEq)]
+pub struct Homework {
     problems: Vec<Problem>,
 }
 
 impl Homework {
-    fn total(&self) -> i32 {
-        self.problems.iter().map(Problem::result).sum()
+    pub fn new(problems: Vec<Problem>) -> Self {
+        Homework { problems }
+    }
+
+    /// The sum of the results of every problem, `None` if it does not fit in a `u64`.
+    pub fn total(&self) -> Option<u64> {
+        self.problems
+            .iter()
+            .try_fold(0u64, |total, problem| total.checked_add(problem.result()?))
     }
 }
 
+/// The whitespace separated tokens of a line, each with the number of characters before it.
+fn tokens(line: &str) -> Vec<(usize, &str)> {
+    line.split_whitespace()
+        .map(|token| {
+            let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+            (line[..offset].chars().count(), token)
+        })
+        .collect()
+}
+
 impl FromStr for Homework {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let vec_of_vec: Vec<Vec<&str>> = s
-            .lines()
-            .map(|line| line.split_whitespace().collect())
-            .collect();
+        let mut line_numbers: Vec<usize> = Vec::new();
+        let mut vec_of_vec: Vec<Vec<(usize, &str)>> = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let row = tokens(line);
+            if row.is_empty() {
+                continue;
+            }
+            if let Some(first) = vec_of_vec.first()
+                && first.len() != row.len()
+            {
+                let kind = ErrorKind::UnevenRow {
+                    expected: first.len(),
+                    found: row.len(),
+                };
+                return Err(ParseError::new(kind, line).at_line(i + 1));
+            }
+            line_numbers.push(i + 1);
+            vec_of_vec.push(row);
+        }
+
+        let rows = vec_of_vec.len();
+        let cols = vec_of_vec.first().map_or(0, Vec::len);
+        if rows < 2 {
+            // Point at the only row, which is not followed by a row of operations.
+            let line_number = line_numbers.last().copied().unwrap_or(1);
+            let line = s.lines().nth(line_number - 1).unwrap_or("");
+            return Err(ParseError::new(ErrorKind::MissingOperations, line.trim())
+                .at_line(line_number)
+                .shift_columns(leading_whitespace(line)));
+        }
+        let ndarray: Array2<(usize, &str)> =
+            Array2::from_shape_vec((rows, cols), vec_of_vec.into_iter().flatten().collect())
+                .expect("rows have the same length");
+
+        let problems = ndarray
+            .columns()
+            .into_iter()
+            .map(|column| {
+                let (offset, operation) = column[rows - 1];
+                let operation: Operation = operation.parse().map_err(|error: ParseError| {
+                    error.at_line(line_numbers[rows - 1]).shift_columns(offset)
+                })?;
+                let terms = column
+                    .iter()
+                    .zip(&line_numbers)
+                    .take(rows - 1)
+                    .map(|(&(offset, term), &line)| {
+                        term.parse().map_err(|error| {
+                            ParseError::new(ErrorKind::InvalidTerm(error), term)
+                                .at_line(line)
+                                .shift_columns(offset)
+                        })
+                    })
+                    .collect::<Result<Vec<u64>, _>>()?;
+                Ok(Problem::new(terms, operation))
+            })
+            .collect::<Result<Vec<Problem>, ParseError>>()?;
+
+        Ok(Homework { problems })
+    }
+}
+
+impl Homework {
+    /// The text of every column: the terms, the operation and the result.
+    fn columns(&self) -> Vec<(Vec<String>, String, String)> {
+        self.problems
+            .iter()
+            .map(|problem| {
+                let terms = problem.terms().iter().map(u64::to_string).collect();
+                let result = problem
+                    .result()
+                    .map_or(String::from("overflow"), |result| result.to_string());
+                (terms, problem.operation().to_string(), result)
+            })
+            .collect()
+    }
+}
+
+/// Every problem in a column of right aligned terms, with its operation and its result below
+/// them.
+impl Render for Homework {
+    fn size(&self) -> (usize, usize) {
+        let columns = self.columns();
+        let width: usize = columns.iter().map(|column| column_width(column) + 1).sum();
+        let height = columns.iter().map(|(terms, _, _)| terms.len()).max();
+        (width.saturating_sub(1), height.unwrap_or(0) + 2)
+    }
+
+    fn render(&self, canvas: &mut dyn Canvas) {
+        let (_, height) = self.size();
+        let mut x = 0;
+        for column in self.columns() {
+            let width = column_width(&column);
+            let (terms, operation, result) = column;
+            for (y, term) in terms.iter().enumerate() {
+                canvas.text(x + width - term.len(), y, term, Style::Normal);
+            }
+            canvas.text(x, height - 2, &operation, Style::Dim);
+            canvas.text(
+                x + width - result.len(),
+                height - 1,
+                &result,
+                Style::Highlight,
+            );
+            x += width + 1;
+        }
+    }
+}
+
+fn column_width((terms, operation, result): &(Vec<String>, String, String)) -> usize {
+    terms
+        .iter()
+        .chain([operation, result])
+        .map(String::len)
+        .max()
+        .unwrap_or(0)
+}
 
-        let ndarray: Array2<&str> = Array2::from(vec_of_vec);
-        let transposed = ndarray.t();
-        let problems = ndarray.rows().into_iter().map(|row| {});
+#[cfg(test)]
+mod tests {
+    use crate::day6::Day6;
+    use crate::render::Ascii;
+    use crate::solution::Solution;
 
-        return Homework {};
+    #[test]
+    fn renders_problems_in_columns() {
+        let homework = Day6::parse(Day6::EXAMPLE).unwrap();
+        let expected = concat!(
+            "  123 328      51  64\n",
+            "   45  64     387  23\n",
+            "    6  98     215 314\n",
+            "*     +   *       +\n",
+            "33210 490 4243455 401\n",
+        );
+        assert_eq!(expected, Ascii::render(&homework));
     }
 }
@@ -1,2 +1,62 @@
+//! Day 6: a homework sheet of problems laid out in columns, with the operation on the last row.
+//!
+//! ```
+//! use advent_of_code_2025::day6::{Homework, Operation, Problem};
+//!
+//! let homework: Homework = "123 328\n 45  64\n*   +".parse()?;
+//! assert_eq!(Some(5535 + 392), homework.total());
+//!
+//! let problem = Problem::new(vec![2, 3, 4], Operation::Multiply);
+//! assert_eq!(Some(24), problem.result());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::render::Render;
+use crate::solution::{NotImplemented, Part, Solution};
+use std::error::Error;
+
+mod error;
+mod generate;
 mod homework;
 mod problem;
+
+pub use error::{ErrorKind, ParseError};
+pub use generate::Params;
+pub use homework::Homework;
+pub use problem::{Operation, Problem};
+
+/// Reads its input with the default [`Solution::read`], unlike the other days: a problem is a
+/// column spanning every row and its operation is on the last row, so no problem can be parsed,
+/// and no row dropped, before the whole input has been read.
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Homework;
+    type PartOne = u64;
+    type PartTwo = u64;
+    type ErrorKind = ErrorKind;
+
+    const EXAMPLE: &'static str = include_str!("test.txt");
+    const PARTS: &'static [Part] = &[Part::One];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_one(homework: &Self::Input) -> Result<Self::PartOne, Box<dyn Error>> {
+        homework
+            .total()
+            .ok_or("grand total does not fit in 64 bits".into())
+    }
+
+    fn part_two(_: &Self::Input) -> Result<Self::PartTwo, Box<dyn Error>> {
+        Err(NotImplemented.into())
+    }
+
+    fn render(homework: Self::Input, _: Option<usize>) -> Option<Box<dyn Render>> {
+        Some(Box::new(homework))
+    }
+}
+
+#[cfg(test)]
+mod tests;
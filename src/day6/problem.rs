@@ -1,27 +1,65 @@
+use crate::day6::error::{ErrorKind, ParseError};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operation {
     Add,
     Multiply,
 }
 
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Operation::Add),
+            "*" => Ok(Operation::Multiply),
+            _ => Err(ParseError::new(ErrorKind::UnknownOperation, s)),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Add => write!(f, "+"),
+            Operation::Multiply => write!(f, "*"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Problem {
-    terms: Vec<i32>,
+    terms: Vec<u64>,
     operation: Operation,
 }
 
 impl Problem {
-    pub fn new(terms: Vec<i32>, operation: Operation) -> Self {
+    pub fn new(terms: Vec<u64>, operation: Operation) -> Self {
         Self { terms, operation }
     }
-    pub fn result(&self) -> i32 {
+    pub fn terms(&self) -> &[u64] {
+        &self.terms
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// The terms added or multiplied, `None` if the result does not fit in a `u64`.
+    pub fn result(&self) -> Option<u64> {
         let initial_value = match self.operation {
             Operation::Add => 0,
             Operation::Multiply => 1,
         };
         self.terms
             .iter()
-            .fold(initial_value, |acc, &term| match self.operation {
-                Operation::Add => acc + term,
-                Operation::Multiply => acc * term,
+            .try_fold(initial_value, |acc: u64, &term| match self.operation {
+                Operation::Add => acc.checked_add(term),
+                Operation::Multiply => acc.checked_mul(term),
             })
     }
 }
@@ -35,6 +73,21 @@ mod tests {
         // 123 * 45 * 6 = 33210
         let problem = Problem::new(vec![123, 45, 6], Operation::Multiply);
         let result = problem.result();
-        assert_eq!(33210, result);
+        assert_eq!(Some(33210), result);
+    }
+
+    #[test]
+    fn overflowing_result_is_none() {
+        let problem = Problem::new(vec![u64::MAX, 2], Operation::Multiply);
+        assert_eq!(None, problem.result());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let problem = Problem::new(vec![123, 45, 6], Operation::Multiply);
+        let json = serde_json::to_string(&problem).unwrap();
+        assert_eq!(r#"{"terms":[123,45,6],"operation":"Multiply"}"#, json);
+        assert_eq!(problem, serde_json::from_str(&json).unwrap());
     }
 }
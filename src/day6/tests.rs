@@ -0,0 +1,40 @@
+use crate::day6::homework::Homework;
+use crate::day6::{Day6, ErrorKind, Params};
+use crate::generate::{Generate, Rng};
+use crate::solution::Solution;
+
+#[test]
+fn day6_parse_error_reports_position() {
+    let error = "123 328\n 45  64\n*   -".parse::<Homework>().unwrap_err();
+    assert_eq!(&ErrorKind::UnknownOperation, error.kind());
+    assert_eq!(3, error.line());
+    assert_eq!(5, error.column());
+    assert_eq!("-", error.text());
+}
+
+#[test]
+fn day6_missing_operations_points_at_last_row() {
+    let error = "\n\n  123 328\n".parse::<Homework>().unwrap_err();
+    assert_eq!(&ErrorKind::MissingOperations, error.kind());
+    assert_eq!(3, error.line());
+    assert_eq!(3, error.column());
+    assert_eq!("123 328", error.text());
+}
+
+#[test]
+fn day6_overflowing_total_is_an_error() {
+    let homework = Day6::parse("18446744073709551615\n2\n*").unwrap();
+    assert!(Day6::part_one(&homework).is_err());
+}
+
+#[test]
+fn day6_generates_terms_for_out_of_range_max_digits() {
+    for max_digits in [0, 20] {
+        let params = Params {
+            max_digits,
+            ..Day6::params(5)
+        };
+        let input = Day6::generate(&params, &mut Rng::new(0));
+        assert!(Day6::parse(&input).is_ok(), "{input}");
+    }
+}
@@ -0,0 +1,249 @@
+//! A terminal UI to step forwards and backwards through a simulation: the rotations of the dial
+//! of day 1, or the rounds of removing paper rolls of day 4.
+
+use crate::render::Ascii;
+use crate::solution::Snapshot;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::io;
+
+/// Number of events page up and page down skip.
+const PAGE: usize = 10;
+
+/// Which snapshot is shown, and the number being typed to jump to.
+pub struct Debugger {
+    snapshots: Vec<Snapshot>,
+    current: usize,
+    jump: String,
+    quit: bool,
+}
+
+impl Debugger {
+    /// A debugger showing the first of `snapshots`, which must not be empty.
+    pub fn new(snapshots: Vec<Snapshot>) -> Self {
+        assert!(!snapshots.is_empty(), "nothing to step through");
+        Debugger {
+            snapshots,
+            current: 0,
+            jump: String::new(),
+            quit: false,
+        }
+    }
+
+    /// Index of the shown snapshot, 0 being the state before the first event.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshots[self.current]
+    }
+
+    pub fn has_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Step with the arrow keys, `h` and `l` or space, page with page up and page down, go to
+    /// the start or end with home and end, or type a number and press enter or `g` to jump to
+    /// that event. `q`, or escape without a number typed, quits.
+    pub fn handle(&mut self, key: KeyCode) {
+        let last = self.snapshots.len() - 1;
+        match key {
+            KeyCode::Char(digit @ '0'..='9') => {
+                self.jump.push(digit);
+                return;
+            }
+            KeyCode::Backspace => {
+                self.jump.pop();
+                return;
+            }
+            KeyCode::Enter | KeyCode::Char('g') if !self.jump.is_empty() => {
+                // Too many digits to parse is past the end anyway.
+                self.current = self.jump.parse().unwrap_or(last).min(last);
+            }
+            KeyCode::Esc if !self.jump.is_empty() => {}
+            KeyCode::Right | KeyCode::Char('l' | ' ') => {
+                self.current = (self.current + 1).min(last)
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.current = self.current.saturating_sub(1),
+            KeyCode::PageDown => self.current = (self.current + PAGE).min(last),
+            KeyCode::PageUp => self.current = self.current.saturating_sub(PAGE),
+            KeyCode::Home => self.current = 0,
+            KeyCode::End => self.current = last,
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            _ => {}
+        }
+        self.jump.clear();
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let snapshot = self.snapshot();
+        let [title, body, help] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        let [state, counters] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Length(30)]).areas(body);
+
+        let title_line = format!(
+            "event {} of {}: {}",
+            self.current,
+            self.snapshots.len() - 1,
+            snapshot.event
+        );
+        frame.render_widget(Line::from(title_line), title);
+        frame.render_widget(
+            Paragraph::new(Ascii::render(snapshot.state.as_ref()))
+                .block(Block::bordered().title("state")),
+            state,
+        );
+        let counter_lines: Vec<Line> = snapshot
+            .counters
+            .iter()
+            .map(|(name, value)| Line::from(format!("{name}: {value}")))
+            .collect();
+        frame.render_widget(
+            Paragraph::new(counter_lines).block(Block::bordered().title("counters")),
+            counters,
+        );
+        let help_line = if self.jump.is_empty() {
+            String::from("←/→ step  PgUp/PgDn ±10  Home/End  <number> g jump  q quit")
+        } else {
+            format!("jump to {}_  (enter to jump, esc to cancel)", self.jump)
+        };
+        frame.render_widget(Line::from(help_line), help);
+    }
+
+    /// Draw on `terminal` and handle key presses until quitting.
+    pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                self.handle(key.code);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Step through `snapshots` in the terminal, restoring it afterwards even on errors.
+pub fn run(snapshots: Vec<Snapshot>) -> io::Result<()> {
+    let mut debugger = Debugger::new(snapshots);
+    let mut terminal = ratatui::init();
+    let result = debugger.run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::debugger::Debugger;
+    use crate::input::EXAMPLE;
+    use crate::registry;
+    use crate::solution::Snapshot;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use ratatui::crossterm::event::KeyCode;
+    use std::error::Error;
+
+    fn snapshots(day: u8) -> Result<Vec<Snapshot>, Box<dyn Error>> {
+        let entry = registry::find(day).unwrap();
+        entry.snapshots(EXAMPLE, entry.example())
+    }
+
+    fn debugger(day: u8) -> Debugger {
+        Debugger::new(snapshots(day).unwrap())
+    }
+
+    #[test]
+    fn counts_dial_passing_zero_over_rotations() {
+        let snapshots = snapshots(1).unwrap();
+        assert_eq!(11, snapshots.len());
+        assert_eq!("L68", snapshots[1].event);
+        assert_eq!(
+            vec![("at zero", 0), ("passed zero", 1)],
+            snapshots[1].counters
+        );
+        let last = snapshots.last().unwrap();
+        assert_eq!(vec![("at zero", 3), ("passed zero", 6)], last.counters);
+    }
+
+    #[test]
+    fn counts_rolls_removed_over_rounds() {
+        let rounds = snapshots(4).unwrap();
+        assert_eq!(
+            vec![("removed this round", 13), ("removed", 13)],
+            rounds[1].counters
+        );
+        let last = rounds.last().unwrap();
+        assert_eq!(43, last.counters[1].1);
+    }
+
+    #[test]
+    fn rejects_days_without_simulation() {
+        assert!(snapshots(2).is_err());
+    }
+
+    #[test]
+    fn steps_within_bounds() {
+        let mut debugger = debugger(1);
+        debugger.handle(KeyCode::Left);
+        assert_eq!(0, debugger.current());
+        debugger.handle(KeyCode::Right);
+        debugger.handle(KeyCode::Char('l'));
+        assert_eq!(2, debugger.current());
+        debugger.handle(KeyCode::PageDown);
+        assert_eq!(10, debugger.current());
+        debugger.handle(KeyCode::Right);
+        assert_eq!(10, debugger.current());
+        debugger.handle(KeyCode::Home);
+        assert_eq!(0, debugger.current());
+        debugger.handle(KeyCode::End);
+        assert_eq!(10, debugger.current());
+    }
+
+    #[test]
+    fn jumps_to_typed_event() {
+        let mut debugger = debugger(1);
+        for key in ['7', 'g'] {
+            debugger.handle(KeyCode::Char(key));
+        }
+        assert_eq!(7, debugger.current());
+        for key in ['9', '9', '9'] {
+            debugger.handle(KeyCode::Char(key));
+        }
+        debugger.handle(KeyCode::Enter);
+        assert_eq!(10, debugger.current());
+        debugger.handle(KeyCode::Char('3'));
+        debugger.handle(KeyCode::Esc);
+        assert!(!debugger.has_quit());
+        assert_eq!(10, debugger.current());
+        debugger.handle(KeyCode::Esc);
+        assert!(debugger.has_quit());
+    }
+
+    #[test]
+    fn draws_event_state_and_counters() {
+        let mut debugger = debugger(4);
+        debugger.handle(KeyCode::Right);
+        let mut terminal = Terminal::new(TestBackend::new(60, 16)).unwrap();
+        terminal.draw(|frame| debugger.draw(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("event 1 of 9: round 1"));
+        assert!(screen.contains("removed this round: 13"));
+        assert!(screen.contains(".......x.."));
+    }
+}
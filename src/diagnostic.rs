@@ -0,0 +1,118 @@
+use crate::error::{ParseError, ParseErrorKind};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A parse error rendered like a compiler error: the file and position, the offending line of
+/// the input and a caret under the offending text.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Diagnostic {
+    day: u8,
+    message: String,
+    file_name: String,
+    line: usize,
+    column: usize,
+    width: usize,
+    source_line: String,
+}
+
+impl Diagnostic {
+    pub fn new<K: ParseErrorKind>(error: &ParseError<K>, file_name: &str, input: &str) -> Self {
+        let source_line = input.lines().nth(error.line() - 1).unwrap_or_default();
+        let remaining = source_line
+            .chars()
+            .count()
+            .saturating_sub(error.column() - 1);
+        let width = error.text().chars().count().min(remaining).max(1);
+        Diagnostic {
+            day: error.day(),
+            message: error.kind().to_string(),
+            file_name: file_name.to_string(),
+            line: error.line(),
+            column: error.column(),
+            width,
+            source_line: source_line.to_string(),
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// What is wrong with the input, without the position.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}", self.message)?;
+        writeln!(
+            f,
+            "{gutter}--> {}:{}:{} (day {})",
+            self.file_name, self.line, self.column, self.day
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl Error for Diagnostic {}
+
+impl<K: ParseErrorKind> ParseError<K> {
+    /// Render this error against the input it was parsed from.
+    pub fn diagnostic(&self, file_name: &str, input: &str) -> Diagnostic {
+        Diagnostic::new(self, file_name, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day1::parse_input;
+    use crate::day5::Database;
+
+    #[test]
+    fn points_caret_at_offending_text() {
+        let input = "L68\nL30\nR4x8\nL5";
+        let error = parse_input(input).unwrap_err();
+        let expected = "\
+invalid rotation amount, invalid digit found in string
+ --> input.txt:3:2 (day 1)
+  |
+3 | R4x8
+  |  ^^^";
+        assert_eq!(expected, error.diagnostic("input.txt", input).to_string());
+    }
+
+    #[test]
+    fn widens_gutter_for_long_line_numbers() {
+        let input = format!("{}\n1\nx2\n", "1-2\n".repeat(10));
+        let error = input.parse::<Database>().unwrap_err();
+        let expected = "\
+invalid ingredient id, invalid digit found in string
+  --> test.txt:13:1 (day 5)
+   |
+13 | x2
+   | ^^";
+        assert_eq!(expected, error.diagnostic("test.txt", &input).to_string());
+    }
+}
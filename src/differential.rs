@@ -0,0 +1,241 @@
+//! Differential testing: run an optimised solver and a naive reference implementation on many
+//! generated cases and shrink any case they disagree on to a minimal counterexample.
+//!
+//! ```
+//! use advent_of_code_2025::differential::{Config, compare, shrink_number, shrink_vec};
+//!
+//! // A "fast" sum that forgets numbers above 100, against the obvious reference.
+//! let result = compare(
+//!     &Config::default(),
+//!     |rng| (0..10).map(|_| rng.range(0..=200)).collect::<Vec<u64>>(),
+//!     |numbers| numbers.iter().filter(|&&n| n <= 100).sum::<u64>(),
+//!     |numbers| numbers.iter().sum::<u64>(),
+//!     |numbers| shrink_vec(numbers, |&n| shrink_number(n, 0)),
+//! );
+//! assert_eq!(vec![101], result.unwrap_err().case);
+//! ```
+
+use crate::generate::Rng;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// Number of generated cases.
+    pub cases: u64,
+    /// Seed of the first case, case `i` is generated from `seed + i`.
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            cases: 500,
+            seed: 0,
+        }
+    }
+}
+
+/// A case the solver and the reference disagree on, after shrinking.
+#[derive(Debug, PartialEq)]
+pub struct Mismatch<T, A> {
+    /// Seed the original case was generated from.
+    pub seed: u64,
+    pub case: T,
+    pub fast: A,
+    pub reference: A,
+    /// Number of times the original case was successfully shrunk.
+    pub shrinks: usize,
+}
+
+impl<T: Debug, A: Debug> Display for Mismatch<T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "solver returned {:?} but reference returned {:?} for {:?} \
+             (seed {}, shrunk {} times)",
+            self.fast, self.reference, self.case, self.seed, self.shrinks
+        )
+    }
+}
+
+impl<T: Debug, A: Debug> Error for Mismatch<T, A> {}
+
+/// Compare `fast` with `reference` on generated cases, returning the first case they disagree on
+/// shrunk by repeatedly replacing it with the first smaller candidate from `shrink` that still
+/// makes them disagree.
+///
+/// `shrink` must only return cases that are smaller in some way, or shrinking never ends.
+pub fn compare<T, A>(
+    config: &Config,
+    mut generate: impl FnMut(&mut Rng) -> T,
+    fast: impl Fn(&T) -> A,
+    reference: impl Fn(&T) -> A,
+    shrink: impl Fn(&T) -> Vec<T>,
+) -> Result<(), Mismatch<T, A>>
+where
+    A: PartialEq,
+{
+    let disagree = |case: &T| {
+        let (fast, reference) = (fast(case), reference(case));
+        (fast != reference).then_some((fast, reference))
+    };
+    for i in 0..config.cases {
+        let seed = config.seed.wrapping_add(i);
+        let mut case = generate(&mut Rng::new(seed));
+        let Some(mut answers) = disagree(&case) else {
+            continue;
+        };
+        let mut shrinks = 0;
+        while let Some((smaller, smaller_answers)) = shrink(&case)
+            .into_iter()
+            .find_map(|smaller| disagree(&smaller).map(|answers| (smaller, answers)))
+        {
+            case = smaller;
+            answers = smaller_answers;
+            shrinks += 1;
+        }
+        let (fast, reference) = answers;
+        return Err(Mismatch {
+            seed,
+            case,
+            fast,
+            reference,
+            shrinks,
+        });
+    }
+    Ok(())
+}
+
+/// Check that generated values are parsed back from their [`Display`] unchanged, shrinking any
+/// value that does not round trip.
+pub fn round_trip<T>(
+    config: &Config,
+    generate: impl FnMut(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+) -> Result<(), Mismatch<T, Option<T>>>
+where
+    T: Display + FromStr + PartialEq + Clone,
+{
+    compare(
+        config,
+        generate,
+        |value| value.to_string().parse().ok(),
+        |value| Some(value.clone()),
+        shrink,
+    )
+}
+
+/// Smaller versions of a list: without its first or second half, without one item, and with one
+/// item shrunk by `shrink_item`.
+pub fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut smaller = Vec::new();
+    if items.len() > 1 {
+        let half = items.len() / 2;
+        smaller.push(items[half..].to_vec());
+        smaller.push(items[..half].to_vec());
+    }
+    for i in 0..items.len() {
+        let mut without = items.to_vec();
+        without.remove(i);
+        smaller.push(without);
+    }
+    for (i, item) in items.iter().enumerate() {
+        for shrunk in shrink_item(item) {
+            let mut with = items.to_vec();
+            with[i] = shrunk;
+            smaller.push(with);
+        }
+    }
+    smaller
+}
+
+/// Numbers between `min` and `n`, closest to `min` first: repeatedly halving the distance to
+/// `n`, and to `min`.
+pub fn shrink_number(n: u64, min: u64) -> Vec<u64> {
+    let mut smaller = Vec::new();
+    let mut distance = n.saturating_sub(min);
+    while distance > 0 {
+        smaller.push(n - distance);
+        smaller.push(min + distance / 2);
+        distance /= 2;
+    }
+    smaller.sort();
+    smaller.dedup();
+    smaller
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::differential::{Config, compare, round_trip, shrink_number, shrink_vec};
+    use std::fmt::{Display, Formatter};
+    use std::num::ParseIntError;
+    use std::str::FromStr;
+
+    #[test]
+    fn agreeing_solvers_pass() {
+        let result = compare(
+            &Config::default(),
+            |rng| rng.range(0..=1000),
+            |&n| n * 2,
+            |&n| n + n,
+            |&n| shrink_number(n, 0),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn shrinks_to_minimal_counterexample() {
+        // Wrongly assumes the list is sorted.
+        let fast = |numbers: &Vec<u64>| numbers.last().copied();
+        let reference = |numbers: &Vec<u64>| numbers.iter().max().copied();
+        let mismatch = compare(
+            &Config::default(),
+            |rng| (0..20).map(|_| rng.range(0..=1000)).collect(),
+            fast,
+            reference,
+            |numbers| shrink_vec(numbers, |&n| shrink_number(n, 0)),
+        )
+        .unwrap_err();
+        assert_eq!(vec![1, 0], mismatch.case);
+        assert_eq!((Some(0), Some(1)), (mismatch.fast, mismatch.reference));
+        assert!(mismatch.shrinks > 0);
+    }
+
+    /// A number that loses its last bit when displayed.
+    #[derive(Debug, PartialEq, Clone)]
+    struct Lossy(u64);
+
+    impl Display for Lossy {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0 & !1)
+        }
+    }
+
+    impl FromStr for Lossy {
+        type Err = ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Lossy(s.parse()?))
+        }
+    }
+
+    #[test]
+    fn finds_values_that_do_not_round_trip() {
+        let mismatch = round_trip(
+            &Config::default(),
+            |rng| Lossy(rng.range(0..=1000)),
+            |Lossy(n)| shrink_number(*n, 0).into_iter().map(Lossy).collect(),
+        )
+        .unwrap_err();
+        assert_eq!(Lossy(1), mismatch.case);
+        assert_eq!(Some(Lossy(0)), mismatch.fast);
+    }
+
+    #[test]
+    fn shrinks_numbers_towards_minimum() {
+        assert_eq!(vec![3, 4, 5, 7, 9, 10], shrink_number(11, 3));
+        assert!(shrink_number(3, 3).is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::io;
+
+/// The reasons a day's input can be rejected, one enum per day.
+pub trait ParseErrorKind: Debug + Display {
+    const DAY: u8;
+}
+
+/// A rejected puzzle input, pointing at the offending text by line and column (both 1-based,
+/// columns counted in characters).
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError<K> {
+    kind: K,
+    line: usize,
+    column: usize,
+    text: String,
+}
+
+impl<K: ParseErrorKind> ParseError<K> {
+    /// An error at the start of the text being parsed, moved into place with [`Self::at_line`]
+    /// and [`Self::shift_columns`] by the parser that knows where that text came from.
+    pub fn new(kind: K, text: impl Into<String>) -> Self {
+        ParseError {
+            kind,
+            line: 1,
+            column: 1,
+            text: text.into(),
+        }
+    }
+
+    pub fn at_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+
+    pub fn shift_columns(self, columns: usize) -> Self {
+        ParseError {
+            column: self.column + columns,
+            ..self
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        K::DAY
+    }
+
+    pub fn kind(&self) -> &K {
+        &self.kind
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl<K: ParseErrorKind> Display for ParseError<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {} `{}`",
+            K::DAY,
+            self.line,
+            self.column,
+            self.kind,
+            self.text
+        )
+    }
+}
+
+impl<K: ParseErrorKind> Error for ParseError<K> {}
+
+/// A failure parsing an input from a reader: reading failed or the text read was rejected.
+#[derive(Debug)]
+pub enum ReadError<K> {
+    Io(io::Error),
+    Parse(ParseError<K>),
+}
+
+impl<K> From<io::Error> for ReadError<K> {
+    fn from(error: io::Error) -> Self {
+        ReadError::Io(error)
+    }
+}
+
+impl<K> From<ParseError<K>> for ReadError<K> {
+    fn from(error: ParseError<K>) -> Self {
+        ReadError::Parse(error)
+    }
+}
+
+impl<K: ParseErrorKind> Display for ReadError<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Io(error) => write!(f, "could not read input: {error}"),
+            ReadError::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl<K: ParseErrorKind + 'static> Error for ReadError<K> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(error) => Some(error),
+            ReadError::Parse(error) => Some(error),
+        }
+    }
+}
+
+/// Number of characters in the leading whitespace of `s`, for reporting columns of trimmed text.
+pub(crate) fn leading_whitespace(s: &str) -> usize {
+    s.chars().take_while(|c| c.is_whitespace()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{ParseError, ParseErrorKind};
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Debug, PartialEq)]
+    struct Broken;
+
+    impl Display for Broken {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "broken")
+        }
+    }
+
+    impl ParseErrorKind for Broken {
+        const DAY: u8 = 42;
+    }
+
+    #[test]
+    fn moves_error_into_place() {
+        let error = ParseError::new(Broken, "x").at_line(3).shift_columns(4);
+        assert_eq!(42, error.day());
+        assert_eq!(3, error.line());
+        assert_eq!(5, error.column());
+        assert_eq!("day 42, line 3, column 5: broken `x`", error.to_string());
+    }
+}
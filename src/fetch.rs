@@ -0,0 +1,230 @@
+use crate::input::{self, InputError, PUZZLE_INPUT};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// Environment variable holding the session cookie of a logged in Advent of Code account.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding the server puzzle inputs are downloaded from.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/etkr/advent-of-code-2025";
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession { session_file: Option<PathBuf> },
+    Status { url: String, status: u16 },
+    Request { url: String, source: ureq::Error },
+    Cache { path: PathBuf, source: io::Error },
+    Input(InputError),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NoSession {
+                session_file: Some(session_file),
+            } => write!(
+                f,
+                "no session, set {SESSION_VAR} or write it to {}",
+                session_file.display()
+            ),
+            FetchError::NoSession { session_file: None } => {
+                write!(f, "no session, set {SESSION_VAR}")
+            }
+            FetchError::Status { url, status } => write!(f, "{url} responded with {status}"),
+            FetchError::Request { url, source } => write!(f, "could not request {url}: {source}"),
+            FetchError::Cache { path, source } => {
+                write!(f, "could not cache input in {}: {source}", path.display())
+            }
+            FetchError::Input(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Request { source, .. } => Some(source),
+            FetchError::Cache { source, .. } => Some(source),
+            FetchError::Input(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<InputError> for FetchError {
+    fn from(error: InputError) -> Self {
+        FetchError::Input(error)
+    }
+}
+
+/// Talks to the puzzle server on behalf of a logged in account.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// A client for `$AOC_BASE_URL`, or the Advent of Code website, using the session from
+    /// `$AOC_SESSION` or the `session` file in the configuration directory.
+    pub fn from_env() -> Result<Self, FetchError> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session_file = input::config_dir().map(|dir| dir.join("session"));
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| fs::read_to_string(session_file.as_ref()?).ok())
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(FetchError::NoSession { session_file })?;
+        Ok(Client::new(&base_url, &session))
+    }
+
+    pub fn with_base_url(self, base_url: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            ..self
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Download the puzzle input of `day`, bypassing the cache.
+    pub fn download(&self, day: u8) -> Result<String, FetchError> {
+        let url = format!("{}/2025/day/{day}/input", self.base_url);
+        let response = self.agent.get(&url).header("Cookie", self.cookie()).call();
+        read_body(url, response)
+    }
+
+    /// Post a form to `path` on the server and return the page it responds with.
+    pub(crate) fn post_form(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, FetchError> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie())
+            .send_form(form.iter().copied());
+        read_body(url, response)
+    }
+}
+
+fn read_body(
+    url: String,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, FetchError> {
+    let request_error = |source| match source {
+        ureq::Error::StatusCode(status) => FetchError::Status {
+            url: url.clone(),
+            status,
+        },
+        source => FetchError::Request {
+            url: url.clone(),
+            source,
+        },
+    };
+    response
+        .map_err(request_error)?
+        .body_mut()
+        .read_to_string()
+        .map_err(request_error)
+}
+
+/// The directory inputs are cached in: the input directory, or the per-user input directory when
+/// none has been set up yet.
+pub fn cache_dir() -> Result<PathBuf, FetchError> {
+    match input::input_dir() {
+        Ok(dir) => Ok(dir),
+        Err(InputError::NoInputDir {
+            config_dir: Some(dir),
+        }) => Ok(dir),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// The path of the puzzle input of `day` in `cache_dir`, downloading it first unless it has been
+/// downloaded before.
+pub fn fetch(client: &Client, cache_dir: &Path, day: u8) -> Result<PathBuf, FetchError> {
+    let path = cache_dir.join(format!("day{day}")).join(PUZZLE_INPUT);
+    if path.is_file() {
+        return Ok(path);
+    }
+    let input = client.download(day)?;
+    let cache_error = |source| FetchError::Cache {
+        path: path.clone(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(cache_error)?;
+    }
+    // Write to a temporary file first so an interrupted download is never mistaken for an input.
+    let partial = path.with_extension("partial");
+    fs::write(&partial, input).map_err(cache_error)?;
+    fs::rename(&partial, &path).map_err(cache_error)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::{Client, FetchError, fetch};
+    use crate::test_server::serve;
+    use std::fs;
+
+    #[test]
+    fn downloads_input_with_session_cookie() {
+        let (base_url, requests) = serve(vec![(200, "L68\nL30\n")]);
+        let client = Client::new(&base_url, "secret\n");
+        assert_eq!("L68\nL30\n", client.download(1).unwrap());
+        let requests = requests.lock().unwrap();
+        assert_eq!("GET /2025/day/1/input HTTP/1.1", requests[0].request_line);
+        assert_eq!(Some("session=secret"), requests[0].header("cookie"));
+    }
+
+    #[test]
+    fn fetches_each_day_only_once() {
+        let (base_url, requests) = serve(vec![(200, "1-2,3-4\n")]);
+        let client = Client::new(&base_url, "secret");
+        let cache = tempfile::tempdir().unwrap();
+        let first = fetch(&client, cache.path(), 2).unwrap();
+        let second = fetch(&client, cache.path(), 2).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(cache.path().join("day2").join("input.txt"), first);
+        assert_eq!("1-2,3-4\n", fs::read_to_string(first).unwrap());
+        assert_eq!(1, requests.lock().unwrap().len());
+    }
+
+    #[test]
+    fn does_not_cache_failed_downloads() {
+        let (base_url, _) = serve(vec![(400, "Please log in")]);
+        let client = Client::new(&base_url, "expired");
+        let cache = tempfile::tempdir().unwrap();
+        let error = fetch(&client, cache.path(), 3).unwrap_err();
+        assert!(matches!(error, FetchError::Status { status: 400, .. }));
+        assert!(!cache.path().join("day3").exists());
+    }
+}
@@ -0,0 +1,136 @@
+use crate::solution::Solution;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// A small pseudo random number generator (SplitMix64).
+///
+/// It is implemented here rather than taken from a crate so that a seed produces the same input
+/// on every platform and with every release.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {start}..={end}");
+        match (end - start).checked_add(1) {
+            // Scale instead of taking the remainder, which would favour small numbers.
+            Some(span) => start + ((self.next_u64() as u128 * span as u128) >> 64) as u64,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index into a collection of `len` items, which must not be empty.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "no index into an empty collection");
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+}
+
+/// Generates valid random puzzle inputs in the format of a day.
+pub trait Generate: Solution {
+    /// The knobs of the format, like the number of lines or the largest number.
+    type Params: Debug + Clone;
+
+    /// Parameters for an input with roughly `size` lines or items, and numbers in the ranges
+    /// seen in the puzzle inputs.
+    fn params(size: usize) -> Self::Params;
+
+    fn generate(params: &Self::Params, rng: &mut Rng) -> String;
+}
+
+/// Generate an input of roughly `size` items for `S` from `seed`.
+pub fn generate<S: Generate>(size: usize, seed: u64) -> String {
+    S::generate(&S::params(size), &mut Rng::new(seed))
+}
+
+/// Check that [`Solution::read`] parses the example, generated inputs and `inputs` to the same
+/// input as [`Solution::parse`].
+#[cfg(test)]
+pub(crate) fn assert_read_matches_parse<S>(inputs: &[&str])
+where
+    S: Generate,
+    S::Input: PartialEq + Debug,
+{
+    let generated = (0..5).map(|seed| generate::<S>(20, seed));
+    let inputs = inputs.iter().map(|input| input.to_string());
+    for input in [S::EXAMPLE.to_string()]
+        .into_iter()
+        .chain(generated)
+        .chain(inputs)
+    {
+        let parsed = S::parse(&input).unwrap();
+        assert_eq!(parsed, S::read(input.as_bytes()).unwrap(), "{input}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::Rng;
+    use crate::registry;
+    use crate::solution::Part;
+
+    #[test]
+    fn same_seed_generates_same_numbers() {
+        let mut first = Rng::new(2025);
+        let mut second = Rng::new(2025);
+        let first: Vec<u64> = (0..8).map(|_| first.range(1..=6)).collect();
+        let second: Vec<u64> = (0..8).map(|_| second.range(1..=6)).collect();
+        assert_eq!(first, second);
+        assert!(first.iter().all(|n| (1..=6).contains(n)));
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    #[should_panic(expected = "no index into an empty collection")]
+    fn picking_from_nothing_panics() {
+        Rng::new(0).pick::<u64>(&[]);
+    }
+
+    #[test]
+    fn generated_inputs_parse_and_solve() {
+        let cases = [0, 1, 20].map(|size| (0..5).map(move |seed| (size, seed)));
+        for entry in registry::days() {
+            for (size, seed) in cases.clone().into_iter().flatten() {
+                let input = entry.generate(size, seed);
+                assert_eq!(input, entry.generate(size, seed));
+                for part in [Part::One, Part::Two] {
+                    if !entry.is_implemented(part) {
+                        continue;
+                    }
+                    if let Err(error) = entry.solve(part, "generated", &input) {
+                        panic!(
+                            "day {} part {part} of size {size} with seed {seed}: {error}\n{input}",
+                            entry.day()
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::registry;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// Environment variable naming the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Name under which the embedded example of a day can be loaded.
+pub const EXAMPLE: &str = "example";
+
+/// Name of a day's personal puzzle input inside the input directory.
+pub const PUZZLE_INPUT: &str = "input.txt";
+
+#[derive(Debug)]
+pub enum InputError {
+    NoInputDir { config_dir: Option<PathBuf> },
+    Missing { path: PathBuf },
+    Unreadable { path: PathBuf, source: io::Error },
+    UnknownDay(u8),
+}
+
+impl InputError {
+    /// Whether the input simply has not been provided, as opposed to being broken.
+    pub fn is_missing(&self) -> bool {
+        matches!(
+            self,
+            InputError::NoInputDir { .. } | InputError::Missing { .. }
+        )
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NoInputDir {
+                config_dir: Some(config_dir),
+            } => write!(
+                f,
+                "no input directory, set {INPUT_DIR_VAR} or create {}",
+                config_dir.display()
+            ),
+            InputError::NoInputDir { config_dir: None } => {
+                write!(f, "no input directory, set {INPUT_DIR_VAR}")
+            }
+            InputError::Missing { path } => write!(f, "input {} does not exist", path.display()),
+            InputError::Unreadable { path, source } => {
+                write!(f, "could not read input {}: {source}", path.display())
+            }
+            InputError::UnknownDay(day) => write!(f, "no solution for day {day}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Unreadable { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The per-user configuration directory, `aoc-2025` in the platform's configuration directory.
+pub fn config_dir() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .or_else(|| env::var_os("APPDATA"))
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_dir.join("aoc-2025"))
+}
+
+/// The per-user input directory, `inputs` in the [`config_dir`].
+pub fn config_input_dir() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("inputs"))
+}
+
+/// The directory holding the `dayN/` input directories: `$AOC_INPUT_DIR` if set, otherwise the
+/// per-user [`config_input_dir`] if it exists.
+pub fn input_dir() -> Result<PathBuf, InputError> {
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        return Ok(PathBuf::from(dir));
+    }
+    match config_input_dir() {
+        Some(dir) if dir.is_dir() => Ok(dir),
+        config_dir => Err(InputError::NoInputDir { config_dir }),
+    }
+}
+
+/// The checkout of the solutions containing `dir`: the closest ancestor with a `Cargo.toml` and a
+/// `src` directory, where days are scaffolded and their answers are kept.
+pub fn find_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file() && dir.join("src").is_dir())
+        .map(Path::to_path_buf)
+}
+
+/// Load the input `name` of `day` from the input directory, or the embedded example of the day
+/// when `name` is [`EXAMPLE`].
+pub fn load(day: u8, name: &str) -> Result<String, InputError> {
+    let entry = registry::find(day).ok_or(InputError::UnknownDay(day))?;
+    if name == EXAMPLE {
+        return Ok(entry.example().to_string());
+    }
+    read(&path(day, name)?)
+}
+
+/// Path of the input `name` of `day` in the input directory.
+pub fn path(day: u8, name: &str) -> Result<PathBuf, InputError> {
+    Ok(input_dir()?.join(format!("day{day}")).join(name))
+}
+
+/// Read an input file, distinguishing inputs that are missing from inputs that cannot be read.
+pub fn read(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing {
+            path: path.to_path_buf(),
+        },
+        _ => InputError::Unreadable {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{EXAMPLE, InputError, find_root, load, read};
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn loads_embedded_example() {
+        let input = load(1, EXAMPLE).unwrap();
+        assert!(input.starts_with("L68\n"));
+    }
+
+    #[test]
+    fn unknown_day_has_no_input() {
+        assert!(matches!(load(26, EXAMPLE), Err(InputError::UnknownDay(26))));
+    }
+
+    #[test]
+    fn missing_file_is_reported_as_missing() {
+        let error = read(Path::new("does/not/exist.txt")).unwrap_err();
+        assert!(error.is_missing());
+        assert_eq!("input does/not/exist.txt does not exist", error.to_string());
+    }
+
+    #[test]
+    fn finds_root_from_nested_directory() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("Cargo.toml"), "").unwrap();
+        let nested = root.path().join("src").join("day1");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(Some(root.path().to_path_buf()), find_root(&nested));
+        assert_eq!(None, find_root(root.path().parent().unwrap()));
+    }
+}
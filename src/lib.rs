@@ -1,6 +1,58 @@
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
+//! Solutions to Advent of Code 2025.
+//!
+//! Every `dayN` module exposes the domain types of that day's puzzle and a `DayN` type
+//! implementing [`solution::Solution`]. The [`registry`] lists every available day.
+//!
+//! Day modules are declared below as `pub mod dayN;`, so rustfmt and clippy see them like any
+//! other module. `aoc new` adds the declaration of the day it scaffolds; a `src/dayN` directory
+//! created by hand must be declared by hand, and the build fails naming the missing declaration
+//! until it is. The build script registers every declared day.
+//!
+//! The solvers need no features. The default `cli` feature builds the `aoc` binary with the
+//! `debugger` feature for the terminal step debugger, `fetch` for downloading inputs and
+//! submitting answers, and `watch` for watch mode. Depend on the library with
+//! `default-features = false` to leave them out.
+//!
+//! With the `serde` feature the domain types implement `Serialize` and `Deserialize`.
+//!
+//! ```
+//! use advent_of_code_2025::day1::Day1;
+//! use advent_of_code_2025::solution::Solution;
+//!
+//! let rotations = Day1::parse("L68\nL30\nR48")?;
+//! assert_eq!(1, Day1::part_one(&rotations)?);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+#[cfg(feature = "debugger")]
+pub mod debugger;
+pub mod diagnostic;
+pub mod differential;
+pub mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod generate;
+pub mod input;
+pub mod registry;
+pub mod render;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+#[cfg(feature = "fetch")]
+pub mod submit;
+pub mod trace;
+#[cfg(feature = "watch")]
+pub mod watch;
+
+#[cfg(all(test, feature = "fetch"))]
+mod test_server;
@@ -0,0 +1,454 @@
+use advent_of_code_2025::baseline;
+use advent_of_code_2025::bench::{self, Config};
+use advent_of_code_2025::debugger;
+use advent_of_code_2025::fetch::{self, Client};
+use advent_of_code_2025::input::InputError;
+use advent_of_code_2025::render::{Ascii, Svg};
+use advent_of_code_2025::scaffold;
+use advent_of_code_2025::solution::Part;
+use advent_of_code_2025::submit::{self, History};
+use advent_of_code_2025::trace::{EventFile, Stderr};
+use advent_of_code_2025::watch::{self, Previous, Watcher};
+use advent_of_code_2025::{answers, input, registry, report, runner};
+use clap::{Args, Parser, Subcommand};
+use std::error::Error;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::{env, fs, io, process};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Checkout of the solutions, defaults to the one containing the current directory
+    #[arg(long, global = true, value_name = "PATH")]
+    root: Option<PathBuf>,
+}
+
+/// The input of one day to work on.
+#[derive(Args, Default)]
+struct InputArgs {
+    /// Puzzle input file or `-` for stdin, defaults to `dayN/input.txt` in the input directory
+    /// and to the example if that does not exist
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Use the example from the puzzle description
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one part of one day
+    Run {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: u8,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Solve every part of every day in parallel and summarise the answers
+    All {
+        /// Use the examples instead of the puzzle inputs
+        #[arg(long)]
+        example: bool,
+        /// Print a JSON report instead of a table, see the `report` module for its schema
+        #[arg(long)]
+        json: bool,
+    },
+    /// List the available days and which parts are implemented
+    List,
+    /// Generate the module of a new day, refusing to overwrite an existing one
+    New {
+        #[arg(long)]
+        day: u8,
+    },
+    /// Solve both parts of a day with the example and the puzzle input and check the answers
+    Check {
+        #[arg(long)]
+        day: u8,
+        /// File with the answers of the previous check, to show which answers changed
+        #[arg(long, value_name = "PATH")]
+        since: Option<PathBuf>,
+    },
+    /// Check a day again whenever its sources, inputs or expected answers change
+    Watch {
+        #[arg(long)]
+        day: u8,
+    },
+    /// Print a random puzzle input of a day, the same every time for the same seed
+    Generate {
+        #[arg(long)]
+        day: u8,
+        /// Defaults to a random seed, which is printed to stderr
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Roughly the number of lines or items of the input
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Draw the dial of day 1, the paper roll grid of day 4 or the homework sheet of day 6
+    Render {
+        #[arg(long)]
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Draw the dial after this many rotations instead of after all of them
+        #[arg(long)]
+        step: Option<usize>,
+        /// Write an SVG picture to this file instead of drawing on the terminal
+        #[arg(long, value_name = "PATH")]
+        svg: Option<PathBuf>,
+    },
+    /// Step through the rotations of day 1 or the removal rounds of day 4 in the terminal
+    Debug {
+        #[arg(long)]
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Log the steps of the solver of day 1, 3, 4 or 5, like rotations or range merges
+    Trace {
+        #[arg(long)]
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Write the events to this file, one per line, instead of to standard error
+        #[arg(long, value_name = "PATH")]
+        events: Option<PathBuf>,
+    },
+    /// Download the puzzle input of a day into the input directory, unless already downloaded
+    Fetch {
+        #[arg(long)]
+        day: u8,
+        /// Server to download from, defaults to `$AOC_BASE_URL` or the Advent of Code website
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Submit an answer, refusing answers that are already known to be wrong
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: u8,
+        /// Answer to submit, defaults to solving the puzzle input of the day
+        #[arg(long)]
+        answer: Option<String>,
+        /// Server to submit to, defaults to `$AOC_BASE_URL` or the Advent of Code website
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Time parsing and each part of every day, or of one day
+    Bench {
+        #[arg(long)]
+        day: Option<u8>,
+        /// Use the examples instead of the puzzle inputs
+        #[arg(long)]
+        example: bool,
+        #[arg(long, default_value_t = Config::default().warmup)]
+        warmup: usize,
+        #[arg(long, default_value_t = Config::default().iterations)]
+        iterations: usize,
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+        /// Record the medians in a baseline file for later runs to compare against, keeping the
+        /// saved medians of stages not benchmarked in this run
+        #[arg(long, value_name = "PATH")]
+        save_baseline: Option<PathBuf>,
+        /// Compare the medians with a baseline file, failing if any stage got slower. The file is
+        /// read before `--save-baseline` updates it, so both can name the same file
+        #[arg(long, value_name = "PATH", conflicts_with = "json")]
+        baseline: Option<PathBuf>,
+        /// Percentage a stage may be slower than the baseline before it is a regression
+        #[arg(long, default_value_t = baseline::DEFAULT_THRESHOLD, requires = "baseline")]
+        threshold: f64,
+    },
+}
+
+/// Read the input selected on the command line, returning its name for diagnostics and its text.
+/// Without a selection the puzzle input is read, or the example if there is no puzzle input.
+fn read_input(day: u8, selected: &InputArgs) -> Result<(String, String), Box<dyn Error>> {
+    let example = || -> Result<_, InputError> {
+        let name = String::from(input::EXAMPLE);
+        Ok((name, input::load(day, input::EXAMPLE)?))
+    };
+    if selected.example {
+        return Ok(example()?);
+    }
+    match &selected.input {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok((String::from("<stdin>"), input))
+        }
+        Some(path) => Ok((path.display().to_string(), input::read(path)?)),
+        None => match read_puzzle_input(day) {
+            Err(error) if error.is_missing() => {
+                eprintln!("{error}, using the example of day {day} instead");
+                Ok(example()?)
+            }
+            read => Ok(read?),
+        },
+    }
+}
+
+/// Read the puzzle input of `day` from the input directory, returning its name for diagnostics
+/// and its text.
+fn read_puzzle_input(day: u8) -> Result<(String, String), InputError> {
+    let path = input::path(day, input::PUZZLE_INPUT)?;
+    Ok((path.display().to_string(), input::read(&path)?))
+}
+
+/// The checkout of the solutions given on the command line, or the one containing the current
+/// directory.
+fn find_root(root: Option<PathBuf>) -> Result<PathBuf, Box<dyn Error>> {
+    match root {
+        Some(root) => Ok(root),
+        None => input::find_root(&env::current_dir()?).ok_or_else(|| {
+            "not in a checkout of the solutions, run from one or pass --root".into()
+        }),
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    match cli.command {
+        Command::Run { day, part, input } => {
+            let (file_name, input) = read_input(day, &input)?;
+            let start = Instant::now();
+            let answer = runner::run(day, part, &file_name, &input)?;
+            let elapsed = start.elapsed();
+            println!("{answer}");
+            eprintln!("day {day} part {part} solved in {elapsed:?}");
+        }
+        Command::All { example, json } => {
+            let name = match example {
+                true => input::EXAMPLE,
+                false => input::PUZZLE_INPUT,
+            };
+            let start = Instant::now();
+            let outcomes = runner::run_all(name);
+            let elapsed = start.elapsed();
+            if json {
+                println!("{}", report::to_json(&outcomes));
+            } else {
+                print!("{}", runner::table(&outcomes));
+                println!("total {elapsed:.1?}");
+            }
+            let failures = outcomes
+                .iter()
+                .filter(|outcome| outcome.status.is_failed())
+                .count();
+            if failures > 0 {
+                return Err(format!("{failures} parts failed").into());
+            }
+        }
+        Command::Check { day, since } => {
+            let path = find_root(cli.root)?.join("answers.txt");
+            let manifest = match fs::read_to_string(&path) {
+                Ok(manifest) => answers::parse_manifest(&manifest)?,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+                Err(error) => {
+                    return Err(format!("could not read {}: {error}", path.display()).into());
+                }
+            };
+            let checks = watch::check(day, &manifest)?;
+            let previous = since.as_deref().map(Previous::load).unwrap_or_default();
+            print!("{}", watch::render(&checks, &previous));
+            if let Some(since) = since {
+                Previous::save(&since, &checks)?;
+            }
+        }
+        Command::Watch { day } => {
+            let root = find_root(cli.root)?;
+            // Sessions share one file per day, emptied so a session only compares its own checks.
+            let since = env::temp_dir().join(format!("aoc-watch-day{day}.txt"));
+            match fs::remove_file(&since) {
+                Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error.into()),
+                _ => {}
+            }
+            let watcher = Watcher::new(&watch::sources(&root, day))?;
+            let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+            loop {
+                // Clear the terminal so only the latest check is shown.
+                print!("\x1b[2J\x1b[H");
+                println!("day {day}, watching for changes");
+                // The sources may have changed, so check with a freshly built binary.
+                process::Command::new(&cargo)
+                    .current_dir(&root)
+                    .args(["run", "--quiet"])
+                    .args(cfg!(not(debug_assertions)).then_some("--release"))
+                    .args(["--", "check", "--day", &day.to_string(), "--since"])
+                    .arg(&since)
+                    .status()?;
+                watcher.wait()?;
+            }
+        }
+        Command::List => {
+            for entry in registry::days() {
+                let status = |part| match entry.is_implemented(part) {
+                    true => "implemented",
+                    false => "not implemented",
+                };
+                println!(
+                    "day {:>2}  part 1: {:<15}  part 2: {}",
+                    entry.day(),
+                    status(Part::One),
+                    status(Part::Two)
+                );
+            }
+        }
+        Command::New { day } => {
+            for path in scaffold::scaffold(&find_root(cli.root)?, day)? {
+                println!("wrote {}", path.display());
+            }
+            eprintln!("run `aoc fetch --day {day}` to download the puzzle input");
+        }
+        Command::Generate { day, seed, size } => {
+            let entry = registry::find(day).ok_or_else(|| format!("No solution for day {day}"))?;
+            let seed = match seed {
+                Some(seed) => seed,
+                None => {
+                    let seed = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+                    eprintln!("seed {seed}");
+                    seed
+                }
+            };
+            print!("{}", entry.generate(size, seed));
+        }
+        Command::Render {
+            day,
+            input,
+            step,
+            svg,
+        } => {
+            let entry = registry::find(day).ok_or_else(|| format!("No solution for day {day}"))?;
+            let (name, input) = read_input(day, &input)?;
+            let state = entry.render(&name, &input, step)?;
+            match svg {
+                Some(path) => fs::write(path, Svg::render(state.as_ref()))?,
+                None => print!("{}", Ascii::render(state.as_ref())),
+            }
+        }
+        Command::Debug { day, input } => {
+            let entry = registry::find(day).ok_or_else(|| format!("No solution for day {day}"))?;
+            let (name, input) = read_input(day, &input)?;
+            debugger::run(entry.snapshots(&name, &input)?)?;
+        }
+        Command::Trace { day, input, events } => {
+            let entry = registry::find(day).ok_or_else(|| format!("No solution for day {day}"))?;
+            let (name, input) = read_input(day, &input)?;
+            match events {
+                Some(path) => {
+                    let mut events = EventFile::new(io::BufWriter::new(fs::File::create(path)?));
+                    entry.trace(&name, &input, &mut events)?;
+                    events.finish()?;
+                }
+                None => entry.trace(&name, &input, &mut Stderr)?,
+            }
+        }
+        Command::Fetch { day, base_url } => {
+            let mut client = Client::from_env()?;
+            if let Some(base_url) = base_url {
+                client = client.with_base_url(&base_url);
+            }
+            let path = fetch::fetch(&client, &fetch::cache_dir()?, day)?;
+            println!("{}", path.display());
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+        } => {
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    // Never the example, its answer is not the answer to the puzzle.
+                    let (file_name, input) = read_puzzle_input(day)?;
+                    runner::run(day, part, &file_name, &input)?
+                }
+            };
+            let mut client = Client::from_env()?;
+            if let Some(base_url) = base_url {
+                client = client.with_base_url(&base_url);
+            }
+            let history_path = submit::history_path(&fetch::cache_dir()?, day);
+            let mut history = History::load(&history_path)?;
+            let part = Part::try_from(part)?;
+            let verdict = submit::submit(&client, &mut history, day, part, &answer, submit::now())?;
+            println!("{answer}: {verdict}");
+        }
+        Command::Bench {
+            day,
+            example,
+            warmup,
+            iterations,
+            json,
+            save_baseline,
+            baseline,
+            threshold,
+        } => {
+            let config = Config { warmup, iterations };
+            let name = if example {
+                input::EXAMPLE
+            } else {
+                input::PUZZLE_INPUT
+            };
+            let mut measurements = Vec::new();
+            for entry in registry::days() {
+                if day.is_some_and(|day| day != entry.day()) {
+                    continue;
+                }
+                let input = match input::load(entry.day(), name) {
+                    Ok(input) => input,
+                    Err(error) if error.is_missing() && day.is_none() => {
+                        eprintln!("skipping day {}: {error}", entry.day());
+                        continue;
+                    }
+                    Err(error) => return Err(error.into()),
+                };
+                measurements.extend(entry.bench(name, &input, &config)?);
+            }
+            let comparisons = baseline::save_and_compare(
+                save_baseline.as_deref(),
+                baseline.as_deref(),
+                name,
+                &measurements,
+            )?;
+            if let (Some(path), Some(comparisons)) = (baseline, comparisons) {
+                print!("{}", baseline::table(&comparisons, threshold));
+                let regressions = comparisons
+                    .iter()
+                    .filter(|comparison| comparison.is_regression(threshold))
+                    .count();
+                if regressions > 0 {
+                    return Err(format!(
+                        "{regressions} stages got more than {threshold}% slower than {}",
+                        path.display()
+                    )
+                    .into());
+                }
+            } else if json {
+                println!("{}", bench::to_json(&measurements));
+            } else {
+                print!("{}", bench::table(&measurements));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
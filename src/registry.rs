@@ -0,0 +1,244 @@
+use crate::bench::{self, Config, Measurement};
+use crate::generate::{self, Generate};
+use crate::render::Render;
+use crate::runner::{Elapsed, Failure, Status};
+use crate::solution::{Part, Snapshot, Solution};
+use crate::trace::Tracer;
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+type Solver = fn(Part, &str, &str) -> Result<String, Box<dyn Error>>;
+type Timer = fn(Part, &str, &str) -> Status;
+type Generator = fn(usize, u64) -> String;
+type Bencher = fn(u8, &str, &str, &Config) -> Result<Vec<Measurement>, Box<dyn Error>>;
+type Renderer = fn(u8, &str, &str, Option<usize>) -> Result<Box<dyn Render>, Box<dyn Error>>;
+type Simulator = fn(u8, &str, &str) -> Result<Vec<Snapshot>, Box<dyn Error>>;
+type Tracing = fn(u8, &str, &str, &mut dyn Tracer) -> Result<(), Box<dyn Error>>;
+
+/// A registered day, discovered from the `src/dayN` directories at build time.
+pub struct Entry {
+    day: u8,
+    example: &'static str,
+    parts: &'static [Part],
+    solve: Solver,
+    time: Timer,
+    bench: Bencher,
+    generate: Generator,
+    render: Renderer,
+    snapshots: Simulator,
+    trace: Tracing,
+}
+
+impl Entry {
+    const fn new<S: Solution + Generate>(day: u8) -> Self {
+        Entry {
+            day,
+            example: S::EXAMPLE,
+            parts: S::PARTS,
+            solve: solve::<S>,
+            time: time::<S>,
+            bench: bench::bench::<S>,
+            generate: generate::generate::<S>,
+            render: render::<S>,
+            snapshots: snapshots::<S>,
+            trace: trace::<S>,
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn example(&self) -> &'static str {
+        self.example
+    }
+
+    pub fn is_implemented(&self, part: Part) -> bool {
+        self.parts.contains(&part)
+    }
+
+    /// Parse the input and solve the given part, returning the answer as text.
+    ///
+    /// Parse errors are returned as a [`Diagnostic`](crate::diagnostic::Diagnostic) quoting
+    /// `file_name`.
+    pub fn solve(
+        &self,
+        part: Part,
+        file_name: &str,
+        input: &str,
+    ) -> Result<String, Box<dyn Error>> {
+        (self.solve)(part, file_name, input)
+    }
+
+    /// Parse the input and solve the given part, timing parsing and solving separately.
+    ///
+    /// Unlike [`Entry::solve`] errors are kept typed, a parse error stays a
+    /// [`Failure::Parse`] with its position.
+    pub fn time(&self, part: Part, file_name: &str, input: &str) -> Status {
+        (self.time)(part, file_name, input)
+    }
+
+    /// Time parsing the input and solving every implemented part, see [`bench::bench`].
+    pub fn bench(
+        &self,
+        file_name: &str,
+        input: &str,
+        config: &Config,
+    ) -> Result<Vec<Measurement>, Box<dyn Error>> {
+        (self.bench)(self.day, file_name, input, config)
+    }
+
+    /// A random input of roughly `size` lines or items, the same for the same `seed`.
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(size, seed)
+    }
+
+    /// Parse the input and draw the puzzle after `step` of its steps, or after all of them, see
+    /// [`Solution::render`].
+    pub fn render(
+        &self,
+        file_name: &str,
+        input: &str,
+        step: Option<usize>,
+    ) -> Result<Box<dyn Render>, Box<dyn Error>> {
+        (self.render)(self.day, file_name, input, step)
+    }
+
+    /// Parse the input and simulate the puzzle one step at a time, see [`Solution::snapshots`].
+    pub fn snapshots(&self, file_name: &str, input: &str) -> Result<Vec<Snapshot>, Box<dyn Error>> {
+        (self.snapshots)(self.day, file_name, input)
+    }
+
+    /// Parse the input and run the solvers, reporting what they do to `tracer`, see
+    /// [`Solution::trace`].
+    pub fn trace(
+        &self,
+        file_name: &str,
+        input: &str,
+        tracer: &mut dyn Tracer,
+    ) -> Result<(), Box<dyn Error>> {
+        (self.trace)(self.day, file_name, input, tracer)
+    }
+}
+
+fn solve<S: Solution>(part: Part, file_name: &str, input: &str) -> Result<String, Box<dyn Error>> {
+    let input = S::parse(input).map_err(|error| error.diagnostic(file_name, input))?;
+    match part {
+        Part::One => Ok(S::part_one(&input)?.to_string()),
+        Part::Two => Ok(S::part_two(&input)?.to_string()),
+    }
+}
+
+fn render<S: Solution>(
+    day: u8,
+    file_name: &str,
+    input: &str,
+    step: Option<usize>,
+) -> Result<Box<dyn Render>, Box<dyn Error>> {
+    let input = S::parse(input).map_err(|error| error.diagnostic(file_name, input))?;
+    S::render(input, step).ok_or_else(|| format!("Nothing to render for day {day}").into())
+}
+
+fn snapshots<S: Solution>(
+    day: u8,
+    file_name: &str,
+    input: &str,
+) -> Result<Vec<Snapshot>, Box<dyn Error>> {
+    let input = S::parse(input).map_err(|error| error.diagnostic(file_name, input))?;
+    S::snapshots(input).ok_or_else(|| format!("Nothing to step through for day {day}").into())
+}
+
+fn trace<S: Solution>(
+    day: u8,
+    file_name: &str,
+    input: &str,
+    tracer: &mut dyn Tracer,
+) -> Result<(), Box<dyn Error>> {
+    let input = S::parse(input).map_err(|error| error.diagnostic(file_name, input))?;
+    S::trace(input, tracer).ok_or_else(|| format!("Nothing to trace for day {day}"))?
+}
+
+fn time<S: Solution>(part: Part, file_name: &str, input: &str) -> Status {
+    let start = Instant::now();
+    let parsed = S::parse(input);
+    let parse = start.elapsed();
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            return Status::Failed {
+                error: Failure::Parse(error.diagnostic(file_name, input)),
+                elapsed: Some(Elapsed {
+                    parse,
+                    solve: Duration::ZERO,
+                }),
+            };
+        }
+    };
+    let start = Instant::now();
+    let (answer, solve) = match part {
+        Part::One => {
+            let answer = S::part_one(&parsed);
+            let solve = start.elapsed();
+            (answer.map(|answer| answer.to_string()), solve)
+        }
+        Part::Two => {
+            let answer = S::part_two(&parsed);
+            let solve = start.elapsed();
+            (answer.map(|answer| answer.to_string()), solve)
+        }
+    };
+    let elapsed = Elapsed { parse, solve };
+    match answer {
+        Ok(answer) => Status::Solved { answer, elapsed },
+        Err(error) => Status::Failed {
+            error: Failure::Solve(error.to_string()),
+            elapsed: Some(elapsed),
+        },
+    }
+}
+
+/// All registered days in ascending order.
+pub fn days() -> &'static [Entry] {
+    DAYS
+}
+
+pub fn find(day: u8) -> Option<&'static Entry> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::{days, find};
+    use crate::solution::Part;
+
+    #[test]
+    fn discovers_every_day_in_order() {
+        let days: Vec<u8> = days().iter().map(|entry| entry.day()).collect();
+        // Newly scaffolded days are registered too, so only the solved days are pinned down.
+        assert_eq!(&[1, 2, 3, 4, 5, 6], &days[..6]);
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn reports_unimplemented_parts() {
+        let day6 = find(6).unwrap();
+        assert!(day6.is_implemented(Part::One));
+        assert!(!day6.is_implemented(Part::Two));
+    }
+
+    #[test]
+    fn dispatches_by_day_number() {
+        let day1 = find(1).unwrap();
+        let answer = day1
+            .solve(Part::One, "example", "L68\nL30\nR48\nL5\nR60")
+            .unwrap();
+        assert_eq!("1", answer);
+    }
+
+    #[test]
+    fn unknown_day_is_not_found() {
+        assert!(find(26).is_none());
+    }
+}
@@ -0,0 +1,211 @@
+//! Pictures of puzzle states, to see what a solver is doing instead of reading numbers.
+//!
+//! A state implementing [`Render`] draws itself on a [`Canvas`] of character cells, which a
+//! back-end turns into a picture: [`Ascii`] for the terminal and [`Svg`] for files.
+//!
+//! ```
+//! use advent_of_code_2025::day4::PaperStorage;
+//! use advent_of_code_2025::render::{Ascii, Svg};
+//!
+//! let paper_storage: PaperStorage = "@@@\n@@@\n.@.".parse()?;
+//! assert_eq!("x@x\n@@@\n.x.\n", Ascii::render(&paper_storage));
+//! assert!(Svg::render(&paper_storage).starts_with("<svg"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::fmt::Write;
+
+/// How a cell stands out. Back-ends without colours ignore it, so glyphs must tell cells apart
+/// on their own.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Style {
+    Normal,
+    /// What the puzzle asks about, like the rolls a forklift can reach.
+    Highlight,
+    /// Background, like empty positions.
+    Dim,
+}
+
+/// A grid of character cells to draw on, `x` counting columns and `y` rows from the top left.
+pub trait Canvas {
+    fn cell(&mut self, x: usize, y: usize, glyph: char, style: Style);
+
+    fn text(&mut self, x: usize, y: usize, text: &str, style: Style) {
+        for (i, glyph) in text.chars().enumerate() {
+            self.cell(x + i, y, glyph, style);
+        }
+    }
+}
+
+/// A puzzle state that can be drawn.
+pub trait Render {
+    /// Columns and rows of the picture, cells outside it are not drawn.
+    fn size(&self) -> (usize, usize);
+
+    fn render(&self, canvas: &mut dyn Canvas);
+}
+
+/// A picture as lines of text, without trailing spaces.
+#[derive(Debug)]
+pub struct Ascii {
+    rows: Vec<Vec<char>>,
+}
+
+impl Ascii {
+    pub fn new(width: usize, height: usize) -> Self {
+        Ascii {
+            rows: vec![vec![' '; width]; height],
+        }
+    }
+
+    pub fn render(state: &dyn Render) -> String {
+        let (width, height) = state.size();
+        let mut canvas = Ascii::new(width, height);
+        state.render(&mut canvas);
+        canvas.finish()
+    }
+
+    pub fn finish(self) -> String {
+        let mut picture = String::new();
+        for row in self.rows {
+            let row: String = row.into_iter().collect();
+            picture.push_str(row.trim_end());
+            picture.push('\n');
+        }
+        picture
+    }
+}
+
+impl Canvas for Ascii {
+    fn cell(&mut self, x: usize, y: usize, glyph: char, _: Style) {
+        if let Some(cell) = self.rows.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *cell = glyph;
+        }
+    }
+}
+
+/// Size of a cell in an SVG picture, in pixels.
+const CELL_WIDTH: usize = 12;
+const CELL_HEIGHT: usize = 20;
+
+/// A picture as an SVG document, every cell a character in a monospace font.
+#[derive(Debug)]
+pub struct Svg {
+    width: usize,
+    height: usize,
+    cells: Vec<(usize, usize, char, Style)>,
+}
+
+impl Svg {
+    pub fn new(width: usize, height: usize) -> Self {
+        Svg {
+            width,
+            height,
+            cells: Vec::new(),
+        }
+    }
+
+    pub fn render(state: &dyn Render) -> String {
+        let (width, height) = state.size();
+        let mut canvas = Svg::new(width, height);
+        state.render(&mut canvas);
+        canvas.finish()
+    }
+
+    pub fn finish(self) -> String {
+        let (width, height) = (self.width * CELL_WIDTH, self.height * CELL_HEIGHT);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             font-family=\"monospace\" font-size=\"16\" text-anchor=\"middle\">\n\
+             <rect width=\"100%\" height=\"100%\" fill=\"#0f0f23\"/>\n"
+        );
+        for (x, y, glyph, style) in self.cells {
+            let (left, top) = (x * CELL_WIDTH, y * CELL_HEIGHT);
+            if style == Style::Highlight {
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{left}\" y=\"{top}\" width=\"{CELL_WIDTH}\" \
+                     height=\"{CELL_HEIGHT}\" fill=\"#ffff66\" fill-opacity=\"0.3\"/>"
+                );
+            }
+            let fill = match style {
+                Style::Normal => "#cccccc",
+                Style::Highlight => "#ffff66",
+                Style::Dim => "#555566",
+            };
+            let escaped = match glyph {
+                '&' => String::from("&amp;"),
+                '<' => String::from("&lt;"),
+                '>' => String::from("&gt;"),
+                glyph => glyph.to_string(),
+            };
+            let _ = writeln!(
+                svg,
+                "<text x=\"{}\" y=\"{}\" fill=\"{fill}\">{escaped}</text>",
+                left + CELL_WIDTH / 2,
+                top + CELL_HEIGHT * 3 / 4
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+impl Canvas for Svg {
+    fn cell(&mut self, x: usize, y: usize, glyph: char, style: Style) {
+        if x < self.width && y < self.height && glyph != ' ' {
+            self.cells.retain(|&(cx, cy, _, _)| (cx, cy) != (x, y));
+            self.cells.push((x, y, glyph, style));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::EXAMPLE;
+    use crate::registry;
+    use crate::render::{Ascii, Canvas, Render, Style, Svg};
+
+    /// Two cells and a highlighted label.
+    struct Sample;
+
+    impl Render for Sample {
+        fn size(&self) -> (usize, usize) {
+            (4, 2)
+        }
+
+        fn render(&self, canvas: &mut dyn Canvas) {
+            canvas.cell(0, 0, '<', Style::Normal);
+            canvas.cell(3, 0, '&', Style::Dim);
+            canvas.text(1, 1, "hello", Style::Highlight);
+        }
+    }
+
+    #[test]
+    fn draws_cells_on_text_clipped_to_size() {
+        assert_eq!("<  &\n hel\n", Ascii::render(&Sample));
+    }
+
+    #[test]
+    fn draws_cells_as_escaped_svg_text() {
+        let svg = Svg::render(&Sample);
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"48\" height=\"40\"")
+        );
+        assert!(svg.contains("<text x=\"6\" y=\"15\" fill=\"#cccccc\">&lt;</text>"));
+        assert!(svg.contains(">&amp;</text>"));
+        assert_eq!(3, svg.matches("fill-opacity").count());
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn renders_states_of_visual_days() {
+        for day in [1, 4, 6] {
+            let entry = registry::find(day).unwrap();
+            let state = entry.render(EXAMPLE, entry.example(), None).unwrap();
+            assert!(!Ascii::render(state.as_ref()).trim().is_empty());
+        }
+        let day2 = registry::find(2).unwrap();
+        assert!(day2.render(EXAMPLE, day2.example(), None).is_err());
+    }
+}
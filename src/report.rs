@@ -0,0 +1,192 @@
+//! Machine readable results of [`run_all`](crate::runner::run_all), for dashboards.
+//!
+//! The report is a single JSON object. Its schema only changes in backwards compatible ways:
+//! fields may be added, but no field is removed, renamed or given another meaning without
+//! incrementing `version`.
+//!
+//! ```text
+//! {
+//!   "version": 1,
+//!   "results": [
+//!     {
+//!       "day": 1,                 // day of the puzzle
+//!       "part": 1,                // 1 or 2
+//!       "input": "input.txt",     // name of the input, "example" for the embedded example
+//!       "status": "solved",       // "solved", "failed", "not-implemented" or "skipped"
+//!       "answer": "1036",         // string, null unless solved
+//!       "parse_ns": 143400,       // time spent parsing, null unless solved or failed, and
+//!                                 // null if the solution panicked or the input was unreadable
+//!       "solve_ns": 27400,        // time spent solving, null when parse_ns is
+//!       "error": null             // null unless failed or skipped, see below
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! An error is an object with a `kind` and a human readable `message`. Kind `parse` means the
+//! parser rejected the input and adds the 1-based `line` and `column` of the offending text, kind
+//! `solve` means the solution returned an error, kind `panic` means it panicked and kind `input`
+//! means the input could not be loaded: it is skipped if it does not exist and failed if it
+//! could not be read.
+//!
+//! ```
+//! use advent_of_code_2025::{input, report, runner};
+//!
+//! let json = report::to_json(&runner::run_all(input::EXAMPLE));
+//! assert!(json.starts_with(r#"{"version":1,"results":[{"day":1,"part":1,"input":"example","#));
+//! ```
+
+use crate::runner::{Failure, Outcome, Status};
+use std::fmt::Write;
+
+/// Version of the schema documented above.
+pub const VERSION: u32 = 1;
+
+/// Render outcomes as a JSON report.
+pub fn to_json(outcomes: &[Outcome]) -> String {
+    let results: Vec<String> = outcomes.iter().map(result).collect();
+    format!(
+        "{{\"version\":{VERSION},\"results\":[{}]}}",
+        results.join(",")
+    )
+}
+
+fn result(outcome: &Outcome) -> String {
+    let (status, answer, error) = match &outcome.status {
+        Status::Solved { answer, .. } => ("solved", string(answer), String::from("null")),
+        Status::Failed { error, .. } => ("failed", String::from("null"), failure(error)),
+        Status::NotImplemented => (
+            "not-implemented",
+            String::from("null"),
+            String::from("null"),
+        ),
+        Status::Skipped(reason) => (
+            "skipped",
+            String::from("null"),
+            format!("{{\"kind\":\"input\",\"message\":{}}}", string(reason)),
+        ),
+    };
+    let (parse, solve) = match outcome.status.elapsed() {
+        Some(elapsed) => (
+            elapsed.parse.as_nanos().to_string(),
+            elapsed.solve.as_nanos().to_string(),
+        ),
+        None => (String::from("null"), String::from("null")),
+    };
+    format!(
+        "{{\"day\":{},\"part\":{},\"input\":{},\"status\":\"{status}\",\"answer\":{answer},\
+         \"parse_ns\":{parse},\"solve_ns\":{solve},\"error\":{error}}}",
+        outcome.day,
+        outcome.part,
+        string(&outcome.input)
+    )
+}
+
+fn failure(failure: &Failure) -> String {
+    match failure {
+        Failure::Parse(diagnostic) => format!(
+            "{{\"kind\":\"parse\",\"message\":{},\"line\":{},\"column\":{}}}",
+            string(diagnostic.message()),
+            diagnostic.line(),
+            diagnostic.column()
+        ),
+        Failure::Solve(message) => {
+            format!("{{\"kind\":\"solve\",\"message\":{}}}", string(message))
+        }
+        Failure::Panic(message) => {
+            format!("{{\"kind\":\"panic\",\"message\":{}}}", string(message))
+        }
+        Failure::Input(message) => {
+            format!("{{\"kind\":\"input\",\"message\":{}}}", string(message))
+        }
+    }
+}
+
+/// A JSON string literal of `s`.
+fn string(s: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(literal, "\\u{:04x}", c as u32);
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+    use crate::report::{string, to_json};
+    use crate::runner::{Elapsed, Failure, Outcome, Status};
+    use crate::solution::Part;
+    use std::time::Duration;
+
+    fn outcome(day: u8, part: Part, status: Status) -> Outcome {
+        Outcome {
+            day,
+            part,
+            input: String::from("input.txt"),
+            status,
+        }
+    }
+
+    #[test]
+    fn renders_every_status() {
+        let elapsed = Elapsed {
+            parse: Duration::from_nanos(20),
+            solve: Duration::from_nanos(30),
+        };
+        let solved = Status::Solved {
+            answer: String::from("1036"),
+            elapsed,
+        };
+        let panicked = Status::Failed {
+            error: Failure::Panic(String::from("overflow")),
+            elapsed: None,
+        };
+        let skipped = Status::Skipped(String::from("input does not exist"));
+        let outcomes = [
+            outcome(1, Part::One, solved),
+            outcome(3, Part::Two, panicked),
+            outcome(4, Part::Two, Status::NotImplemented),
+            outcome(6, Part::One, skipped),
+        ];
+        let expected = "{\"version\":1,\"results\":[\
+            {\"day\":1,\"part\":1,\"input\":\"input.txt\",\"status\":\"solved\",\"answer\":\"1036\",\
+            \"parse_ns\":20,\"solve_ns\":30,\"error\":null},\
+            {\"day\":3,\"part\":2,\"input\":\"input.txt\",\"status\":\"failed\",\"answer\":null,\
+            \"parse_ns\":null,\"solve_ns\":null,\"error\":{\"kind\":\"panic\",\"message\":\"overflow\"}},\
+            {\"day\":4,\"part\":2,\"input\":\"input.txt\",\"status\":\"not-implemented\",\
+            \"answer\":null,\"parse_ns\":null,\"solve_ns\":null,\"error\":null},\
+            {\"day\":6,\"part\":1,\"input\":\"input.txt\",\"status\":\"skipped\",\"answer\":null,\
+            \"parse_ns\":null,\"solve_ns\":null,\
+            \"error\":{\"kind\":\"input\",\"message\":\"input does not exist\"}}]}";
+        assert_eq!(expected, to_json(&outcomes));
+    }
+
+    #[test]
+    fn reports_parse_errors_with_position() {
+        let day3 = registry::find(3).unwrap();
+        let status = day3.time(Part::One, "input.txt", "987\n81x9");
+        let json = to_json(&[outcome(3, Part::One, status)]);
+        assert!(json.contains("\"status\":\"failed\",\"answer\":null,\"parse_ns\":"));
+        assert!(json.contains(
+            "\"error\":{\"kind\":\"parse\",\"message\":\"invalid battery 'x', expected a digit\",\
+             \"line\":2,\"column\":3}"
+        ));
+    }
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!("\"a \\\"b\\\"\\n\\\\\\u0007\"", string("a \"b\"\n\\\u{7}"));
+    }
+}
@@ -0,0 +1,245 @@
+use crate::diagnostic::Diagnostic;
+use crate::input::{self, InputError};
+use crate::registry;
+use crate::solution::Part;
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Solve one part of one day for the given puzzle input and return the answer as text.
+///
+/// `file_name` names the input in parse error diagnostics.
+pub fn run(day: u8, part: u8, file_name: &str, input: &str) -> Result<String, Box<dyn Error>> {
+    let entry = registry::find(day).ok_or_else(|| format!("No solution for day {day}"))?;
+    entry.solve(Part::try_from(part)?, file_name, input)
+}
+
+/// Time spent parsing the input and solving one part from the parsed input.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Elapsed {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Elapsed {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// Why solving one part of one day failed.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Failure {
+    /// The input was rejected by the parser.
+    Parse(Diagnostic),
+    /// The solution returned an error.
+    Solve(String),
+    Panic(String),
+    /// The input exists but could not be read.
+    Input(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Parse(diagnostic) => write!(
+                f,
+                "{}:{}:{}: {}",
+                diagnostic.file_name(),
+                diagnostic.line(),
+                diagnostic.column(),
+                diagnostic.message()
+            ),
+            Failure::Solve(message) => write!(f, "{message}"),
+            Failure::Panic(message) => write!(f, "panicked: {message}"),
+            Failure::Input(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// How solving one part of one day went.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Status {
+    Solved {
+        answer: String,
+        elapsed: Elapsed,
+    },
+    Failed {
+        error: Failure,
+        /// `None` if the time spent is not known, like when the solution panicked.
+        elapsed: Option<Elapsed>,
+    },
+    NotImplemented,
+    /// The input does not exist, with the reason.
+    Skipped(String),
+}
+
+impl Status {
+    /// The status of a part whose input could not be loaded: skipped if the input does not
+    /// exist, failed if it could not be read.
+    pub fn from_input_error(error: &InputError) -> Status {
+        match error.is_missing() {
+            true => Status::Skipped(error.to_string()),
+            false => Status::Failed {
+                error: Failure::Input(error.to_string()),
+                elapsed: None,
+            },
+        }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Status::Failed { .. })
+    }
+
+    pub fn elapsed(&self) -> Option<Elapsed> {
+        match self {
+            Status::Solved { elapsed, .. } => Some(*elapsed),
+            Status::Failed { elapsed, .. } => *elapsed,
+            Status::NotImplemented | Status::Skipped(_) => None,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved { answer, .. } => write!(f, "{answer}"),
+            Status::Failed { error, .. } => write!(f, "error: {error}"),
+            Status::NotImplemented => write!(f, "not implemented"),
+            Status::Skipped(reason) => write!(f, "skipped: {reason}"),
+        }
+    }
+}
+
+/// How solving one part of one day with the input called `input` went.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub status: Status,
+}
+
+/// Solve every part of every registered day with the input called `name`, each in its own
+/// thread, returning the outcomes ordered by day and part.
+pub fn run_all(name: &str) -> Vec<Outcome> {
+    let inputs: Vec<_> = registry::days()
+        .iter()
+        .map(|entry| (entry, input::load(entry.day(), name)))
+        .collect();
+    thread::scope(|scope| {
+        let handles: Vec<_> = inputs
+            .iter()
+            .flat_map(|(entry, input)| [Part::One, Part::Two].map(|part| (entry, input, part)))
+            .map(|(entry, input, part)| {
+                let handle = match input {
+                    Ok(input) if entry.is_implemented(part) => {
+                        Some(scope.spawn(move || entry.time(part, name, input)))
+                    }
+                    _ => None,
+                };
+                (entry, input, part, handle)
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|(entry, input, part, handle)| {
+                let status = match (input, handle) {
+                    (Err(error), _) => Status::from_input_error(error),
+                    (Ok(_), None) => Status::NotImplemented,
+                    (Ok(_), Some(handle)) => {
+                        handle.join().unwrap_or_else(|payload| Status::Failed {
+                            error: Failure::Panic(panic_message(payload)),
+                            elapsed: None,
+                        })
+                    }
+                };
+                Outcome {
+                    day: entry.day(),
+                    part,
+                    input: name.to_string(),
+                    status,
+                }
+            })
+            .collect()
+    })
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => String::from("unknown cause"),
+        },
+    }
+}
+
+/// Render outcomes as a table for the terminal.
+pub fn table(outcomes: &[Outcome]) -> String {
+    let mut table = format!(
+        "{:>3}  {:>4}  {:>12}  {}\n",
+        "day", "part", "time", "answer"
+    );
+    for outcome in outcomes {
+        let elapsed = match outcome.status.elapsed() {
+            Some(elapsed) => format!("{:.1?}", elapsed.total()),
+            None => String::from("-"),
+        };
+        let _ = writeln!(
+            table,
+            "{:>3}  {:>4}  {:>12}  {}",
+            outcome.day,
+            outcome.part.to_string(),
+            elapsed,
+            outcome.status
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{EXAMPLE, InputError};
+    use crate::registry;
+    use crate::runner::{Failure, Status, run_all};
+    use crate::solution::Part;
+    use std::io;
+    use std::path::PathBuf;
+
+    #[test]
+    fn runs_every_day_and_part() {
+        let outcomes = run_all(EXAMPLE);
+        assert_eq!(registry::days().len() * 2, outcomes.len());
+        assert!(outcomes.iter().all(|outcome| !outcome.status.is_failed()));
+        let day1 = &outcomes[0];
+        assert_eq!((1, Part::One), (day1.day, day1.part));
+        assert!(matches!(&day1.status, Status::Solved { answer, .. } if answer == "3"));
+        let day6 = &outcomes[11];
+        assert_eq!((6, Part::Two), (day6.day, day6.part));
+        assert_eq!(Status::NotImplemented, day6.status);
+    }
+
+    #[test]
+    fn skips_missing_inputs_and_fails_unreadable_ones() {
+        let path = PathBuf::from("inputs/day2/input.txt");
+        let missing = InputError::Missing { path: path.clone() };
+        assert!(matches!(
+            Status::from_input_error(&missing),
+            Status::Skipped(_)
+        ));
+        let unreadable = InputError::Unreadable {
+            path,
+            source: io::Error::other("Is a directory"),
+        };
+        let status = Status::from_input_error(&unreadable);
+        assert!(status.is_failed());
+        assert_eq!(None, status.elapsed());
+        assert!(matches!(status, Status::Failed {
+            error: Failure::Input(message),
+            ..
+        } if message.ends_with("Is a directory")));
+    }
+}
@@ -0,0 +1,215 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+/// The files of a new day module and the templates they are generated from, in which `{{day}}`
+/// is replaced by the day number.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("mod.rs", include_str!("../templates/day/mod.rs.tmpl")),
+    ("error.rs", include_str!("../templates/day/error.rs.tmpl")),
+    (
+        "generate.rs",
+        include_str!("../templates/day/generate.rs.tmpl"),
+    ),
+    ("puzzle.rs", include_str!("../templates/day/puzzle.rs.tmpl")),
+    ("tests.rs", include_str!("../templates/day/tests.rs.tmpl")),
+    ("test.txt", include_str!("../templates/day/test.txt.tmpl")),
+];
+
+/// Name of the answers manifest in the root of the repository.
+const ANSWERS: &str = "answers.txt";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(u8),
+    Exists { path: PathBuf },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => {
+                write!(f, "no puzzle on day {day}, days run from 1 to 25")
+            }
+            ScaffoldError::Exists { path } => {
+                write!(
+                    f,
+                    "{} already exists, refusing to overwrite it",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io { path, source } => {
+                write!(f, "could not write {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Generate the module of `day` in the `src/dayN` directory of the repository at `root`, its
+/// declaration in `src/lib.rs`, and commented out placeholders for its answers in the answers
+/// manifest, returning the files written.
+///
+/// The build script registers the declared module, so it is part of the next build.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+    let dir = root.join("src").join(format!("day{day}"));
+    // Creating the directory fails if it exists, so nothing of an existing day is overwritten.
+    match fs::create_dir(&dir) {
+        Ok(()) => {}
+        Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {
+            return Err(ScaffoldError::Exists { path: dir });
+        }
+        Err(source) => return Err(ScaffoldError::Io { path: dir, source }),
+    }
+    let mut written = Vec::new();
+    for (name, template) in TEMPLATES {
+        let path = dir.join(name);
+        let contents = template.replace("{{day}}", &day.to_string());
+        fs::write(&path, contents).map_err(|source| ScaffoldError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        written.push(path);
+    }
+
+    let lib = root.join("src").join("lib.rs");
+    if lib.is_file() {
+        declare(&lib, day)?;
+        written.push(lib);
+    }
+
+    let answers = root.join(ANSWERS);
+    if answers.is_file() {
+        let io_error = |source| ScaffoldError::Io {
+            path: answers.clone(),
+            source,
+        };
+        let contents = fs::read_to_string(&answers).map_err(io_error)?;
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(&answers)
+            .map_err(io_error)?;
+        // The placeholders would be glued onto a last entry without a line break.
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            writeln!(file).map_err(io_error)?;
+        }
+        for part in [1, 2] {
+            for input in ["example", "input.txt"] {
+                writeln!(file, "# {day} {part} {input} ?").map_err(io_error)?;
+            }
+        }
+        written.push(answers);
+    }
+    Ok(written)
+}
+
+/// Add `pub mod dayN;` to the module declarations in `lib`, in the order rustfmt keeps them.
+fn declare(lib: &Path, day: u8) -> Result<(), ScaffoldError> {
+    let io_error = |source| ScaffoldError::Io {
+        path: lib.to_path_buf(),
+        source,
+    };
+    let contents = fs::read_to_string(lib).map_err(io_error)?;
+    let name = format!("day{day}");
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let modules: Vec<(usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, line.strip_prefix("pub mod ")?.strip_suffix(';')?)))
+        .collect();
+    let position = match modules
+        .iter()
+        .find(|(_, module)| module_order(module) > module_order(&name))
+    {
+        Some(&(i, _)) => i,
+        None => modules.last().map_or(lines.len(), |&(i, _)| i + 1),
+    };
+    let declaration = format!("pub mod {name};");
+    lines.insert(position, &declaration);
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    fs::write(lib, contents).map_err(io_error)
+}
+
+/// Sort key of a module name, ordering `dayN` modules by their number like rustfmt does.
+fn module_order(module: &str) -> (&str, Option<u8>) {
+    match module.strip_prefix("day").map(str::parse) {
+        Some(Ok(day)) => ("day", Some(day)),
+        _ => (module, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::parse_manifest;
+    use crate::scaffold::{ScaffoldError, scaffold};
+    use std::fs;
+
+    #[test]
+    fn generates_day_module() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(root.path().join("src")).unwrap();
+        fs::write(root.path().join("answers.txt"), "1 1 example 3\n").unwrap();
+        let lib = "pub mod day1;\npub mod day10;\npub mod debugger;\n";
+        fs::write(root.path().join("src").join("lib.rs"), lib).unwrap();
+        let written = scaffold(root.path(), 7).unwrap();
+        let dir = root.path().join("src").join("day7");
+        for name in [
+            "mod.rs",
+            "error.rs",
+            "generate.rs",
+            "puzzle.rs",
+            "tests.rs",
+            "test.txt",
+        ] {
+            assert!(written.contains(&dir.join(name)), "{name} was not written");
+        }
+        let module = fs::read_to_string(dir.join("mod.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day7 {"));
+        assert!(!module.contains("{{day}}"));
+        let answers = fs::read_to_string(root.path().join("answers.txt")).unwrap();
+        assert!(answers.starts_with("1 1 example 3\n# 7 1 example ?\n"));
+        let lib = fs::read_to_string(root.path().join("src").join("lib.rs")).unwrap();
+        assert_eq!(
+            "pub mod day1;\npub mod day7;\npub mod day10;\npub mod debugger;\n",
+            lib
+        );
+    }
+
+    #[test]
+    fn ends_answers_without_line_break_before_placeholders() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(root.path().join("src")).unwrap();
+        fs::write(root.path().join("answers.txt"), "1 1 example 3").unwrap();
+        scaffold(root.path(), 7).unwrap();
+        let answers = fs::read_to_string(root.path().join("answers.txt")).unwrap();
+        assert!(answers.starts_with("1 1 example 3\n# 7 1 example ?\n"));
+        assert_eq!(1, parse_manifest(&answers).unwrap().len());
+    }
+
+    #[test]
+    fn refuses_to_overwrite_existing_day() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("src").join("day3");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("mod.rs"), "// solved").unwrap();
+        let error = scaffold(root.path(), 3).unwrap_err();
+        assert!(matches!(error, ScaffoldError::Exists { .. }));
+        assert_eq!("// solved", fs::read_to_string(dir.join("mod.rs")).unwrap());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::error::{ParseError, ParseErrorKind, ReadError};
+use crate::render::Render;
+use crate::trace::Tracer;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+
+/// A day of the puzzle: parses the input once and solves both parts from it.
+pub trait Solution {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+    type ErrorKind: ParseErrorKind + 'static;
+
+    /// The example from the puzzle description, embedded so it is always available.
+    const EXAMPLE: &'static str;
+
+    /// The parts that have been solved, so unfinished days can be reported without running them.
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError<Self::ErrorKind>>;
+
+    /// Parse the input from a reader. Days whose input can be parsed record by record override
+    /// this to never hold more than one line of text in memory.
+    fn read(mut reader: impl BufRead) -> Result<Self::Input, ReadError<Self::ErrorKind>> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(Self::parse(&input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Box<dyn Error>>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Box<dyn Error>>;
+
+    /// A picture of the puzzle after `step` of its steps, or after all of them. Days with
+    /// something to draw override this.
+    fn render(_input: Self::Input, _step: Option<usize>) -> Option<Box<dyn Render>> {
+        None
+    }
+
+    /// The states of the puzzle before its first step and after every step, to step through in
+    /// the debugger. Days with a simulation override this.
+    fn snapshots(_input: Self::Input) -> Option<Vec<Snapshot>> {
+        None
+    }
+
+    /// Run the solvers, reporting what they do to `tracer`. Days with traced solvers override
+    /// this.
+    fn trace(_input: Self::Input, _tracer: &mut dyn Tracer) -> Option<Result<(), Box<dyn Error>>> {
+        None
+    }
+}
+
+/// The state of a simulation after an event.
+pub struct Snapshot {
+    /// What happened to reach this state, like `R48`.
+    pub event: String,
+    pub state: Box<dyn Render>,
+    /// Running totals, like the number of times the dial passed zero.
+    pub counters: Vec<(&'static str, u64)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Box<dyn Error>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("No part {value}, puzzles only have parts 1 and 2").into()),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Returned by parts that have not been solved yet.
+#[derive(Debug)]
+pub struct NotImplemented;
+
+impl Display for NotImplemented {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not implemented")
+    }
+}
+
+impl Error for NotImplemented {}
@@ -0,0 +1,481 @@
+use crate::fetch::{Client, FetchError};
+use crate::solution::Part;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+
+/// Seconds to wait after a wrong answer, the server refuses answers for at least a minute.
+const COOLDOWN: u64 = 60;
+
+/// Name of the file in a day's input directory where every submitted answer is recorded.
+pub const HISTORY: &str = "submissions.txt";
+
+/// The server's response to a submitted answer.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// An answer was submitted too recently, with the seconds left to wait if the server said.
+    Wait(Option<u64>),
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    /// Interpret the page the server responds with after submitting an answer.
+    pub fn from_response(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::Wait(Self::time_left(page))
+        } else if page.contains("Did you already complete it") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Parse "You have 1m 23s left to wait" into seconds.
+    fn time_left(page: &str) -> Option<u64> {
+        let (_, rest) = page.split_once("You have ")?;
+        let (time, _) = rest.split_once(" left to wait")?;
+        time.split_whitespace()
+            .map(|amount| {
+                if let Some(minutes) = amount.strip_suffix('m') {
+                    minutes.parse::<u64>().ok().map(|minutes| minutes * 60)
+                } else if let Some(seconds) = amount.strip_suffix('s') {
+                    seconds.parse().ok()
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+
+    /// Seconds to wait after this verdict before submitting another answer.
+    fn cooldown(&self) -> u64 {
+        match self {
+            Verdict::Wait(Some(seconds)) => *seconds,
+            Verdict::Wait(None) => COOLDOWN,
+            verdict if verdict.is_wrong() => COOLDOWN,
+            _ => 0,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::Wait(Some(seconds)) => write!(f, "wait {seconds}"),
+            Verdict::Wait(None) => write!(f, "wait"),
+            Verdict::AlreadySolved => write!(f, "already-solved"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            Some(("wait", seconds)) => seconds
+                .parse()
+                .map(|seconds| Verdict::Wait(Some(seconds)))
+                .map_err(|_| format!("invalid wait `{s}`")),
+            Some(_) => Err(format!("unknown verdict `{s}`")),
+            None => match s {
+                "correct" => Ok(Verdict::Correct),
+                "too-high" => Ok(Verdict::TooHigh),
+                "too-low" => Ok(Verdict::TooLow),
+                "incorrect" => Ok(Verdict::Incorrect),
+                "wait" => Ok(Verdict::Wait(None)),
+                "already-solved" => Ok(Verdict::AlreadySolved),
+                "unknown" => Ok(Verdict::Unknown),
+                _ => Err(format!("unknown verdict `{s}`")),
+            },
+        }
+    }
+}
+
+/// One submitted answer, at `time` in seconds since the Unix epoch.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Attempt {
+    pub time: u64,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}",
+            self.time, self.part, self.answer, self.verdict
+        )
+    }
+}
+
+impl FromStr for Attempt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('\t').collect();
+        let [time, part, answer, verdict] = fields[..] else {
+            return Err(format!("expected four tab separated fields in `{s}`"));
+        };
+        let part: u8 = part.parse().map_err(|_| format!("invalid part `{part}`"))?;
+        Ok(Attempt {
+            time: time.parse().map_err(|_| format!("invalid time `{time}`"))?,
+            part: Part::try_from(part).map_err(|error| error.to_string())?,
+            answer: answer.to_string(),
+            verdict: verdict.parse()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    InvalidAnswer {
+        answer: String,
+    },
+    Solved {
+        answer: Option<String>,
+    },
+    Duplicate {
+        verdict: Verdict,
+    },
+    KnownTooHigh {
+        bound: String,
+    },
+    KnownTooLow {
+        bound: String,
+    },
+    Cooldown {
+        seconds: u64,
+    },
+    Fetch(FetchError),
+    History {
+        path: PathBuf,
+        source: io::Error,
+    },
+    CorruptHistory {
+        path: PathBuf,
+        line: usize,
+        reason: String,
+    },
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::InvalidAnswer { answer } => {
+                write!(
+                    f,
+                    "{answer:?} cannot be submitted, an answer is one word without control characters"
+                )
+            }
+            SubmitError::Solved {
+                answer: Some(answer),
+            } => write!(f, "already solved, the answer was {answer}"),
+            SubmitError::Solved { answer: None } => write!(f, "already solved"),
+            SubmitError::Duplicate { verdict } => {
+                write!(f, "already submitted, the verdict was {verdict}")
+            }
+            SubmitError::KnownTooHigh { bound } => write!(f, "{bound} was already too high"),
+            SubmitError::KnownTooLow { bound } => write!(f, "{bound} was already too low"),
+            SubmitError::Cooldown { seconds } => {
+                write!(f, "answered too recently, wait {seconds}s")
+            }
+            SubmitError::Fetch(error) => write!(f, "{error}"),
+            SubmitError::History { path, source } => {
+                write!(f, "could not access {}: {source}", path.display())
+            }
+            SubmitError::CorruptHistory { path, line, reason } => {
+                write!(f, "{}:{line}: {reason}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for SubmitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SubmitError::Fetch(error) => Some(error),
+            SubmitError::History { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<FetchError> for SubmitError {
+    fn from(error: FetchError) -> Self {
+        SubmitError::Fetch(error)
+    }
+}
+
+/// Every answer submitted for one day, kept in a file so answers are never resubmitted.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Load the history from `path`, which does not need to exist yet.
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        let history_error = |source| SubmitError::History {
+            path: path.to_path_buf(),
+            source,
+        };
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(history_error(error)),
+        };
+        let attempts = contents
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.parse().map_err(|reason| SubmitError::CorruptHistory {
+                    path: path.to_path_buf(),
+                    line: i + 1,
+                    reason,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(History {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Refuse answers that are empty or contain whitespace or control characters, which could not
+    /// be recorded, answers the history already knows the verdict of, and answers submitted before
+    /// the cooldown of the previous attempt has passed.
+    pub fn check(&self, part: Part, answer: &str, now: u64) -> Result<(), SubmitError> {
+        if answer.is_empty() || answer.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            let answer = answer.to_string();
+            return Err(SubmitError::InvalidAnswer { answer });
+        }
+        let attempts = || self.attempts.iter().filter(|attempt| attempt.part == part);
+        if let Some(attempt) = attempts()
+            .find(|attempt| matches!(attempt.verdict, Verdict::Correct | Verdict::AlreadySolved))
+        {
+            let answer = (attempt.verdict == Verdict::Correct).then(|| attempt.answer.clone());
+            return Err(SubmitError::Solved { answer });
+        }
+        if let Some(attempt) =
+            attempts().find(|attempt| attempt.answer == answer && attempt.verdict.is_wrong())
+        {
+            let verdict = attempt.verdict.clone();
+            return Err(SubmitError::Duplicate { verdict });
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            for attempt in attempts() {
+                let bound = attempt.answer.parse::<i128>();
+                match (&attempt.verdict, bound) {
+                    (Verdict::TooHigh, Ok(bound)) if value >= bound => {
+                        let bound = attempt.answer.clone();
+                        return Err(SubmitError::KnownTooHigh { bound });
+                    }
+                    (Verdict::TooLow, Ok(bound)) if value <= bound => {
+                        let bound = attempt.answer.clone();
+                        return Err(SubmitError::KnownTooLow { bound });
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let ready_at = self
+            .attempts
+            .iter()
+            .map(|attempt| attempt.time + attempt.verdict.cooldown())
+            .max()
+            .unwrap_or(0);
+        if now < ready_at {
+            return Err(SubmitError::Cooldown {
+                seconds: ready_at - now,
+            });
+        }
+        Ok(())
+    }
+
+    /// Append an attempt to the history file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), SubmitError> {
+        let history_error = |source| SubmitError::History {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(history_error)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(history_error)?;
+        writeln!(file, "{attempt}").map_err(history_error)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+/// The history file of `day` in the input directory `input_dir`.
+pub fn history_path(input_dir: &Path, day: u8) -> PathBuf {
+    input_dir.join(format!("day{day}")).join(HISTORY)
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Submit `answer` for `part` of `day` unless the history rules it out, and record the verdict.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Verdict, SubmitError> {
+    history.check(part, answer, now)?;
+    let level = part.to_string();
+    let path = format!("/2025/day/{day}/answer");
+    let page = client.post_form(&path, &[("level", &level), ("answer", answer)])?;
+    let verdict = Verdict::from_response(&page);
+    history.record(Attempt {
+        time: now,
+        part,
+        answer: answer.to_string(),
+        verdict: verdict.clone(),
+    })?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::Client;
+    use crate::solution::Part;
+    use crate::submit::{History, SubmitError, Verdict, history_path, submit};
+    use crate::test_server::serve;
+
+    const CORRECT: &str = "<article><p>That's the right answer! You are one gold star closer.</p>";
+    const TOO_HIGH: &str =
+        "<article><p>That's not the right answer; your answer is too high.</p></article>";
+
+    #[test]
+    fn interprets_server_responses() {
+        assert_eq!(Verdict::Correct, Verdict::from_response(CORRECT));
+        assert_eq!(Verdict::TooHigh, Verdict::from_response(TOO_HIGH));
+        let wait = "You gave an answer too recently; you have to wait after submitting an \
+                    answer before trying again.  You have 1m 23s left to wait.";
+        assert_eq!(Verdict::Wait(Some(83)), Verdict::from_response(wait));
+        let garbled = "You gave an answer too recently.  You have 1é left to wait.";
+        assert_eq!(Verdict::Wait(None), Verdict::from_response(garbled));
+        let solved = "You don't seem to be solving the right level.  Did you already complete it?";
+        assert_eq!(Verdict::AlreadySolved, Verdict::from_response(solved));
+        assert_eq!(Verdict::Unknown, Verdict::from_response("<html></html>"));
+    }
+
+    #[test]
+    fn posts_answer_and_records_verdict() {
+        let (base_url, requests) = serve(vec![(200, CORRECT)]);
+        let client = Client::new(&base_url, "secret");
+        let dir = tempfile::tempdir().unwrap();
+        let path = history_path(dir.path(), 3);
+        let mut history = History::load(&path).unwrap();
+
+        let verdict = submit(&client, &mut history, 3, Part::Two, "1234", 1000).unwrap();
+
+        assert_eq!(Verdict::Correct, verdict);
+        let requests = requests.lock().unwrap();
+        assert_eq!("POST /2025/day/3/answer HTTP/1.1", requests[0].request_line);
+        assert_eq!("level=2&answer=1234", requests[0].body);
+        let reloaded = History::load(&path).unwrap();
+        assert_eq!(history.attempts(), reloaded.attempts());
+    }
+
+    #[test]
+    fn never_resubmits_known_answers() {
+        let (base_url, requests) = serve(vec![(200, TOO_HIGH)]);
+        let client = Client::new(&base_url, "secret");
+        let dir = tempfile::tempdir().unwrap();
+        let mut history = History::load(&history_path(dir.path(), 1)).unwrap();
+        submit(&client, &mut history, 1, Part::One, "500", 0).unwrap();
+
+        let duplicate = submit(&client, &mut history, 1, Part::One, "500", 1000);
+        assert!(matches!(duplicate, Err(SubmitError::Duplicate { .. })));
+        let higher = submit(&client, &mut history, 1, Part::One, "600", 1000);
+        assert!(matches!(higher, Err(SubmitError::KnownTooHigh { .. })));
+        assert_eq!(1, requests.lock().unwrap().len());
+    }
+
+    #[test]
+    fn rejects_answers_that_cannot_be_recorded() {
+        let (base_url, requests) = serve(vec![(200, CORRECT)]);
+        let client = Client::new(&base_url, "secret");
+        let dir = tempfile::tempdir().unwrap();
+        let path = history_path(dir.path(), 2);
+        let mut history = History::load(&path).unwrap();
+
+        for answer in ["", "12\t34", "12\n34", "12 34", "12\u{7f}"] {
+            let invalid = submit(&client, &mut history, 2, Part::One, answer, 0);
+            assert!(matches!(invalid, Err(SubmitError::InvalidAnswer { .. })));
+        }
+        assert!(requests.lock().unwrap().is_empty());
+        assert!(History::load(&path).unwrap().attempts().is_empty());
+    }
+
+    #[test]
+    fn enforces_cooldown_after_wrong_answer() {
+        let (base_url, _) = serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(&base_url, "secret");
+        let dir = tempfile::tempdir().unwrap();
+        let mut history = History::load(&history_path(dir.path(), 1)).unwrap();
+        submit(&client, &mut history, 1, Part::One, "500", 0).unwrap();
+
+        let early = submit(&client, &mut history, 1, Part::One, "400", 10);
+        assert!(matches!(early, Err(SubmitError::Cooldown { seconds: 50 })));
+        let verdict = submit(&client, &mut history, 1, Part::One, "400", 60).unwrap();
+        assert_eq!(Verdict::Correct, verdict);
+
+        let solved = submit(&client, &mut history, 1, Part::One, "300", 1000);
+        assert!(matches!(
+            solved,
+            Err(SubmitError::Solved { answer: Some(_) })
+        ));
+    }
+}
@@ -0,0 +1,348 @@
+//! Structured tracing of what solvers do, to debug wrong answers without sprinkling `println!`.
+//!
+//! Solvers with a `_traced` variant report [`Event`]s to a [`Tracer`]. The untraced
+//! versions pass [`NoTrace`], for which events are never built. [`Stderr`] logs events as they
+//! happen and [`EventFile`] writes them one per line for later analysis.
+//!
+//! ```
+//! use advent_of_code_2025::day1::{RotationDirection, turn_dial};
+//! use advent_of_code_2025::trace::{Event, EventFile};
+//!
+//! let mut events = EventFile::new(Vec::new());
+//! turn_dial(&[RotationDirection::Left(68)], &mut events);
+//! let events = String::from_utf8(events.finish()?)?;
+//! assert_eq!("rotate L68 position=82 passed_zero=1\n", events);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::Write;
+
+/// A step of a solver: what it did, what it did it to and the values it ended up with.
+///
+/// Events only hold primitive values, so a day reports its steps without this module knowing
+/// its types.
+///
+/// ```
+/// use advent_of_code_2025::trace::{Event, Value};
+///
+/// let event = Event::new("push").subject(9u64).field("depth", 1usize);
+/// assert_eq!("push 9 depth=1", event.to_string());
+/// assert_eq!(Some(&Value::Unsigned(1)), event.get("depth"));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Event {
+    name: &'static str,
+    subjects: Vec<Value>,
+    fields: Vec<(&'static str, Value)>,
+}
+
+impl Event {
+    pub fn new(name: &'static str) -> Self {
+        Event {
+            name,
+            subjects: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Add a value the step acted on, written after the name.
+    pub fn subject(mut self, value: impl Into<Value>) -> Self {
+        self.subjects.push(value.into());
+        self
+    }
+
+    /// Add a value the step ended up with, written as `name=value`.
+    pub fn field(mut self, name: &'static str, value: impl Into<Value>) -> Self {
+        self.fields.push((name, value.into()));
+        self
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn subjects(&self) -> &[Value] {
+        &self.subjects
+    }
+
+    /// The value of the field called `name`.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        for subject in &self.subjects {
+            write!(f, " {subject}")?;
+        }
+        for (name, value) in &self.fields {
+            write!(f, " {name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A value in an [`Event`].
+#[derive(Debug, PartialEq, Clone)]
+pub enum Value {
+    Unsigned(u64),
+    Signed(i64),
+    Bool(bool),
+    Text(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Unsigned(value) => write!(f, "{value}"),
+            Value::Signed(value) => write!(f, "{value}"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::Unsigned(value)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Value::Unsigned(value.into())
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        Value::Unsigned(value as u64)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Signed(value.into())
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+/// Receives the events of a solver.
+pub trait Tracer {
+    fn record_event(&mut self, event: Event);
+
+    /// Whether events are recorded, `false` to let solvers skip building them.
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    /// Record the event built by `event`, which is only called if events are recorded.
+    fn record(&mut self, event: impl FnOnce() -> Event)
+    where
+        Self: Sized,
+    {
+        if self.is_enabled() {
+            self.record_event(event());
+        }
+    }
+}
+
+/// Ignores every event.
+#[derive(Debug, Default)]
+pub struct NoTrace;
+
+impl<T: Tracer + ?Sized> Tracer for &mut T {
+    fn record_event(&mut self, event: Event) {
+        (**self).record_event(event);
+    }
+
+    fn is_enabled(&self) -> bool {
+        (**self).is_enabled()
+    }
+}
+
+impl Tracer for NoTrace {
+    fn record_event(&mut self, _: Event) {}
+
+    fn is_enabled(&self) -> bool {
+        false
+    }
+}
+
+/// Logs every event to standard error.
+#[derive(Debug, Default)]
+pub struct Stderr;
+
+impl Tracer for Stderr {
+    fn record_event(&mut self, event: Event) {
+        eprintln!("{event}");
+    }
+}
+
+/// Writes every event as a line to a writer, like a file.
+#[derive(Debug)]
+pub struct EventFile<W: Write> {
+    writer: W,
+    /// The first error writing, after which events are dropped.
+    error: Option<io::Error>,
+}
+
+impl<W: Write> EventFile<W> {
+    pub fn new(writer: W) -> Self {
+        EventFile {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Flush the writer and return it, or the first error writing.
+    pub fn finish(mut self) -> io::Result<W> {
+        match self.error {
+            Some(error) => Err(error),
+            None => {
+                self.writer.flush()?;
+                Ok(self.writer)
+            }
+        }
+    }
+}
+
+impl<W: Write> Tracer for EventFile<W> {
+    fn record_event(&mut self, event: Event) {
+        if self.error.is_none()
+            && let Err(error) = writeln!(self.writer, "{event}")
+        {
+            self.error = Some(error);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day5::IngredientIdRange;
+    use crate::input::EXAMPLE;
+    use crate::registry;
+    use crate::trace::{Event, EventFile, NoTrace, Tracer, Value};
+    use std::io;
+    use std::io::Write;
+
+    /// Keeps the events to look at them.
+    #[derive(Default)]
+    struct Collect(Vec<Event>);
+
+    impl Tracer for Collect {
+        fn record_event(&mut self, event: Event) {
+            self.0.push(event);
+        }
+    }
+
+    fn events(day: u8) -> Vec<Event> {
+        let entry = registry::find(day).unwrap();
+        let mut events = Collect::default();
+        entry.trace(EXAMPLE, entry.example(), &mut events).unwrap();
+        events.0
+    }
+
+    #[test]
+    fn traces_every_rotation() {
+        let events = events(1);
+        assert_eq!(10, events.len());
+        assert_eq!(
+            "rotate L68 position=82 passed_zero=1",
+            events[0].to_string()
+        );
+    }
+
+    #[test]
+    fn traces_stack_pushes_and_pops() {
+        let events = events(3);
+        let count = |name| events.iter().filter(|event| event.name() == name).count();
+        // Every bank ends with 12 values on the stack.
+        assert_eq!(4 * 12, count("push") - count("pop"));
+        assert_eq!(
+            Event::new("push").subject(9u64).field("depth", 1usize),
+            events[0]
+        );
+    }
+
+    #[test]
+    fn traces_every_window_of_every_round() {
+        let events = events(4);
+        // The example grid has 100 positions, checked once per round.
+        let accessible = events[..100]
+            .iter()
+            .filter(|event| event.get("accessible") == Some(&Value::Bool(true)))
+            .count();
+        assert_eq!(13, accessible);
+        assert_eq!(0, events.len() % 100);
+    }
+
+    #[test]
+    fn traces_range_merges() {
+        let events = events(5);
+        assert!(!events.is_empty());
+        let range = |value: &Value| value.to_string().parse::<IngredientIdRange>().unwrap();
+        assert!(events.iter().all(|event| {
+            let [first, second] = event.subjects() else {
+                return false;
+            };
+            let (first, second) = (range(first), range(second));
+            let merged = range(event.get("into").unwrap());
+            event.name() == "merge" && first.overlaps(&second) && merged == first.merge(&second)
+        }));
+    }
+
+    #[test]
+    fn rejects_days_without_tracing() {
+        let day2 = registry::find(2).unwrap();
+        let error = day2
+            .trace(EXAMPLE, day2.example(), &mut Collect::default())
+            .unwrap_err();
+        assert_eq!("Nothing to trace for day 2", error.to_string());
+    }
+
+    /// Fails every write.
+    #[derive(Debug)]
+    struct Broken;
+
+    impl Write for Broken {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn no_trace_never_builds_events() {
+        NoTrace.record(|| unreachable!("event built for NoTrace"));
+    }
+
+    #[test]
+    fn event_file_keeps_first_write_error() {
+        let mut file = EventFile::new(Broken);
+        file.record_event(Event::new("push").subject(1u64));
+        file.record_event(Event::new("pop").subject(1u64));
+        assert_eq!("disk full", file.finish().unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,265 @@
+use crate::answers::Answer;
+use crate::input::{self, EXAMPLE, InputError, PUZZLE_INPUT};
+use crate::registry;
+use crate::runner::Status;
+use crate::solution::Part;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher as _};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+use std::{fs, io};
+
+/// How long to keep collecting changes after the first one, editors often write a file in
+/// several steps.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// One part of one day solved with the example or the puzzle input, and the answer the answers
+/// manifest expects if it has one.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Check {
+    pub input: String,
+    pub part: Part,
+    pub status: Status,
+    pub expected: Option<String>,
+}
+
+impl Check {
+    pub fn is_failed(&self) -> bool {
+        match (&self.status, &self.expected) {
+            (Status::Solved { answer, .. }, Some(expected)) => answer != expected,
+            (status, _) => status.is_failed(),
+        }
+    }
+}
+
+/// Parse and solve both parts of `day` with the example and the puzzle input.
+pub fn check(day: u8, manifest: &[Answer]) -> Result<Vec<Check>, InputError> {
+    let entry = registry::find(day).ok_or(InputError::UnknownDay(day))?;
+    let mut checks = Vec::new();
+    for name in [EXAMPLE, PUZZLE_INPUT] {
+        let input = input::load(day, name);
+        for part in [Part::One, Part::Two] {
+            let status = match &input {
+                Err(error) => Status::from_input_error(error),
+                Ok(_) if !entry.is_implemented(part) => Status::NotImplemented,
+                Ok(input) => entry.time(part, name, input),
+            };
+            let expected = manifest
+                .iter()
+                .find(|answer| answer.day == day && answer.part == part && answer.input == name)
+                .map(|answer| answer.answer.clone());
+            checks.push(Check {
+                input: name.to_string(),
+                part,
+                status,
+                expected,
+            });
+        }
+    }
+    Ok(checks)
+}
+
+/// The answers of an earlier check, to point out which answers changed since.
+#[derive(Debug, PartialEq, Default)]
+pub struct Previous {
+    answers: Vec<(String, Part, String)>,
+}
+
+impl Previous {
+    /// Load the answers saved by [`Previous::save`], nothing is known if they cannot be read.
+    pub fn load(path: &Path) -> Self {
+        let contents = fs::read_to_string(path).unwrap_or_default();
+        let answers = contents
+            .lines()
+            .filter_map(|line| {
+                let [input, part, answer] = line.split('\t').collect::<Vec<_>>()[..] else {
+                    return None;
+                };
+                let part = Part::try_from(part.parse::<u8>().ok()?).ok()?;
+                Some((input.to_string(), part, answer.to_string()))
+            })
+            .collect();
+        Previous { answers }
+    }
+
+    pub fn save(path: &Path, checks: &[Check]) -> io::Result<()> {
+        let mut contents = String::new();
+        for check in checks {
+            if let Status::Solved { answer, .. } = &check.status {
+                let _ = writeln!(contents, "{}\t{}\t{answer}", check.input, check.part);
+            }
+        }
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, input: &str, part: Part) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|(i, p, _)| i == input && *p == part)
+            .map(|(_, _, answer)| answer.as_str())
+    }
+}
+
+/// Render checks compactly: the answer or error of every part with every input, whether it
+/// matches the expected answer and what it was before if it changed.
+pub fn render(checks: &[Check], previous: &Previous) -> String {
+    let mut rendered = String::new();
+    for check in checks {
+        let mut line = format!("{:<9}  part {}  {}", check.input, check.part, check.status);
+        if let Status::Solved { answer, elapsed } = &check.status {
+            match &check.expected {
+                Some(expected) if expected == answer => line.push_str("  ok"),
+                Some(expected) => {
+                    let _ = write!(line, "  FAIL expected {expected}");
+                }
+                None => {}
+            }
+            if let Some(before) = previous.get(&check.input, check.part)
+                && before != answer
+            {
+                let _ = write!(line, "  (was {before})");
+            }
+            let _ = write!(line, "  {:.1?}", elapsed.total());
+        }
+        let _ = writeln!(rendered, "{line}");
+    }
+    let failed = checks.iter().filter(|check| check.is_failed()).count();
+    let passed = checks
+        .iter()
+        .filter(|check| check.expected.is_some() && !check.is_failed())
+        .count();
+    let _ = writeln!(rendered, "{passed} passed, {failed} failed");
+    rendered
+}
+
+/// The files a check of `day` depends on: its source directory, its directory in the input
+/// directory and the answers manifest, as far as they exist.
+pub fn sources(root: &Path, day: u8) -> Vec<PathBuf> {
+    let mut sources = vec![root.join("src").join(format!("day{day}"))];
+    if let Ok(dir) = input::input_dir() {
+        sources.push(dir.join(format!("day{day}")));
+    }
+    sources.push(root.join("answers.txt"));
+    sources.retain(|path| path.exists());
+    sources
+}
+
+/// Filesystem notifications for a set of files and directories.
+pub struct Watcher {
+    events: Receiver<notify::Result<notify::Event>>,
+    // Dropping the watcher stops the notifications.
+    _watcher: RecommendedWatcher,
+}
+
+impl Watcher {
+    pub fn new(paths: &[PathBuf]) -> notify::Result<Self> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        for path in paths {
+            watcher.watch(path, RecursiveMode::Recursive)?;
+        }
+        Ok(Watcher {
+            events,
+            _watcher: watcher,
+        })
+    }
+
+    /// Block until something changes, then until changes settle, returning the changed paths.
+    pub fn wait(&self) -> notify::Result<Vec<PathBuf>> {
+        let mut changed = Vec::new();
+        while changed.is_empty() {
+            let event = self
+                .events
+                .recv()
+                .map_err(|_| notify::Error::generic("watcher stopped"))?;
+            Self::collect(event?, &mut changed);
+        }
+        while let Ok(event) = self.events.recv_timeout(SETTLE) {
+            Self::collect(event?, &mut changed);
+        }
+        Ok(changed)
+    }
+
+    /// Add the paths of `event` to `changed` unless it only reports that a file was read, as
+    /// checking reads every watched file.
+    fn collect(event: notify::Event, changed: &mut Vec<PathBuf>) {
+        if event.kind.is_access() || event.kind.is_other() {
+            return;
+        }
+        for path in event.paths {
+            if !changed.contains(&path) {
+                changed.push(path);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::parse_manifest;
+    use crate::runner::{Elapsed, Status};
+    use crate::solution::Part;
+    use crate::watch::{Check, Previous, Watcher, check, render};
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn checks_example_against_manifest() {
+        let manifest = parse_manifest("6 1 example 4277556\n").unwrap();
+        let checks = check(6, &manifest).unwrap();
+        assert_eq!(4, checks.len());
+        assert_eq!(Some("4277556"), checks[0].expected.as_deref());
+        assert!(matches!(&checks[0].status, Status::Solved { answer, .. } if answer == "4277556"));
+        assert_eq!(Status::NotImplemented, checks[1].status);
+        assert!(checks.iter().all(|check| !check.is_failed()));
+    }
+
+    #[test]
+    fn renders_failures_and_changed_answers() {
+        let solved = |answer: &str| Status::Solved {
+            answer: answer.to_string(),
+            elapsed: Elapsed {
+                parse: Duration::from_micros(1),
+                solve: Duration::from_micros(2),
+            },
+        };
+        let checks = [
+            Check {
+                input: String::from("example"),
+                part: Part::One,
+                status: solved("3"),
+                expected: Some(String::from("3")),
+            },
+            Check {
+                input: String::from("input.txt"),
+                part: Part::One,
+                status: solved("1037"),
+                expected: Some(String::from("1036")),
+            },
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("previous.txt");
+        fs::write(&path, "example\t1\t3\ninput.txt\t1\t1036\n").unwrap();
+        let expected = "\
+example    part 1  3  ok  3.0µs
+input.txt  part 1  1037  FAIL expected 1036  (was 1036)  3.0µs
+1 passed, 1 failed
+";
+        assert_eq!(expected, render(&checks, &Previous::load(&path)));
+        Previous::save(&path, &checks).unwrap();
+        assert_eq!(
+            Some("1037"),
+            Previous::load(&path).get("input.txt", Part::One)
+        );
+    }
+
+    #[test]
+    fn notices_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let watcher = Watcher::new(&[dir.path().to_path_buf()]).unwrap();
+        let path = dir.path().join("input.txt");
+        fs::write(&path, "L68\n").unwrap();
+        assert!(watcher.wait().unwrap().contains(&path));
+    }
+}